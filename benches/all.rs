@@ -1,63 +1,65 @@
-use criterion::criterion_main;
-// this macro generates a module containing the benchmarks for a single year
+use criterion::{criterion_group, criterion_main, Criterion};
+use rust_aoc::{input::normalize, Overrides};
+use std::{fs::read_to_string, hint::black_box};
 
-macro_rules! benchmark_year {
-    ($year:tt; $($day:tt),*) => {
-        mod $year {
-            use criterion::{criterion_group, Criterion};
-            use std::hint::black_box;
-
-            $(
-            pub fn $day(c: &mut Criterion) {
-                use std::fs::read_to_string;
-                use rust_aoc::$year::$day::{parse, solve_part_one, solve_part_two};
-
-                static DAY: &str = stringify!($day);
+fn input_path(year: u32, day: u32) -> String {
+    format!("data/input/year{}/day{:02}.txt", year, day)
+}
 
-                let path = "data/input/".to_owned() + stringify!($year) + "/" + stringify!($day) + ".txt";
+// the input the way run reads it, None for a day without an input
+fn read_input(path: &str) -> Option<String> {
+    read_to_string(path).ok().map(|input| normalize(&input).0)
+}
 
-                let input = read_to_string(path).unwrap();
+// benchmarks parsing and both parts separately for every registered solver with an input
+fn days(c: &mut Criterion) {
+    for solver in rust_aoc::solvers() {
+        let Some(input) = read_input(&input_path(solver.year, solver.day)) else {
+            continue;
+        };
 
-                c.bench_function(&format!("{}: Parse", DAY), |b| {
-                    b.iter(|| parse(black_box(&input)))
-                });
+        let name = format!("year{}::day{:02}", solver.year, solver.day);
 
-                c.bench_function(&format!("{}: Part 1", DAY), |b| {
-                    let input = parse(&input);
-                    b.iter(|| solve_part_one(black_box(&input)))
-                });
+        // a day that cannot parse its input is broken, rather than left out of the results
+        if let Err(e) = solver.parse(&input, &Overrides::default()) {
+            panic!("{}: {}", name, e);
+        }
 
-                c.bench_function(&format!("{}: Part 2", DAY), |b| {
-                    let input = parse(&input);
-                    b.iter(|| solve_part_two(black_box(&input)))
-                });
-            })*
+        c.bench_function(&format!("{}: Parse", name), |b| {
+            b.iter(|| solver.parse(black_box(&input), &Overrides::default()))
+        });
 
+        c.bench_function(&format!("{}: Part 1", name), |b| {
+            let input = solver.parse(&input, &Overrides::default()).unwrap();
+            b.iter(|| input.part_one())
+        });
 
-            pub fn compare(c: &mut Criterion) {
-                let mut group = c.benchmark_group("compare");
+        c.bench_function(&format!("{}: Part 2", name), |b| {
+            let input = solver.parse(&input, &Overrides::default()).unwrap();
+            b.iter(|| input.part_two())
+        });
+    }
+}
 
-                $(
-                let path = "data/input/".to_owned() + stringify!($year) + "/" + stringify!($day) + ".txt";
+// benchmarks reading, parsing and solving both parts for comparison between days
+fn compare(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare");
 
-                group.bench_function(&format!("[compare] {}", stringify!($day)), |b| {
-                    b.iter(|| rust_aoc::$year::$day::solve(&path))
-                });
-                )*
-                group.finish();
-            }
+    for solver in rust_aoc::solvers() {
+        let path = input_path(solver.year, solver.day);
 
-            criterion_group!(benches, compare, $($day, )*);
+        if read_to_string(&path).is_err() {
+            continue;
         }
-    };
-}
 
-benchmark_year!(year2024; 
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
-    day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, 
-    day21, day22, day23, day24, day25);
+        group.bench_function(
+            format!("[compare] year{}::day{:02}", solver.year, solver.day),
+            |b| b.iter(|| solver.solve(&read_input(&path).unwrap())),
+        );
+    }
 
-benchmark_year!(year2025;
-    day01);
+    group.finish();
+}
 
-criterion_main!(year2024::benches, year2025::benches);
+criterion_group!(benches, compare, days);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+// generates the registry of the solutions from the module declarations, every `pub mod yearYYYY;`
+// in lib.rs and every `pub mod dayDD;` in the mod.rs of its year is registered in order of year
+// and day, so that a new day only needs its module declared in the mod.rs of its year
+
+use std::{env, fs, path::Path};
+
+// the number in names like year2024 or day01
+fn number(name: &str, prefix: &str, digits: usize) -> Option<u32> {
+    let number = name.strip_prefix(prefix)?;
+
+    if number.len() != digits || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    number.parse().ok()
+}
+
+// the numbers of the modules like `pub mod day01;` declared in the file, in order
+fn declared(path: &Path, prefix: &str, digits: usize) -> Vec<u32> {
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let mut res: Vec<u32> = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e))
+        .lines()
+        .filter_map(|line| {
+            let name = line.trim().strip_prefix("pub mod ")?.strip_suffix(';')?;
+
+            number(name, prefix, digits)
+        })
+        .collect();
+
+    res.sort();
+    res
+}
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+
+    let mut res = String::from("static YEARS: &[&[Solver]] = &[\n");
+
+    for year in declared(&src.join("lib.rs"), "year", 4) {
+        let module = src.join(format!("year{}", year)).join("mod.rs");
+
+        res += "    &[\n";
+
+        for day in declared(&module, "day", 2) {
+            res += &format!(
+                "        Solver::new::<year{}::day{:02}::Puzzle>(),\n",
+                year, day
+            );
+        }
+
+        res += "    ],\n";
+    }
+
+    res += "];\n";
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("solvers.rs");
+    fs::write(&out, res).unwrap_or_else(|e| panic!("could not write {}: {}", out.display(), e));
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+// the answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(u64),
+    Signed(i64),
+    String(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::String(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(n as u64)
+            }
+        })*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Signed(n as i64)
+            }
+        })*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_string())
+    }
+}
+
+// a part that might not find a solution for its input
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::Integer(42), 42usize.into());
+        assert_eq!(Answer::Signed(-3), (-3i64).into());
+        assert_eq!(Answer::String("6,1".to_string()), "6,1".into());
+        assert_eq!(Answer::Unsolved, Option::<u32>::None.into());
+        assert_eq!(Answer::Integer(7), Some(7u32).into());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("42", Answer::Integer(42).to_string());
+        assert_eq!("-3", Answer::Signed(-3).to_string());
+        assert_eq!("co,de,ka,ta", Answer::from("co,de,ka,ta").to_string());
+        assert_eq!("unsolved", Answer::Unsolved.to_string());
+    }
+}
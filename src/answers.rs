@@ -0,0 +1,305 @@
+// known answers for the puzzle inputs, stored per year in a small subset of toml:
+//
+// [day01]
+// part_one = 2264607
+// part_two = "19457120"
+//
+// the sidecars of the examples use the same keys without a day section, followed by the
+// parameters the example needs:
+//
+// part_one = 12
+//
+// [params]
+// width = 11
+
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use crate::{Answer, Overrides};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    days: BTreeMap<u32, [Option<String>; 2]>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Check::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut res = String::new();
+        let mut chars = quoted.chars();
+
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    'n' => res.push('\n'),
+                    't' => res.push('\t'),
+                    c => res.push(c),
+                },
+                c => res.push(c),
+            }
+        }
+
+        // only a comment may follow the closing quote
+        let rest = chars.as_str().trim();
+
+        if rest.is_empty() || rest.starts_with('#') {
+            Some(res)
+        } else {
+            None
+        }
+    } else {
+        let value = value.split('#').next().unwrap().trim();
+
+        if !value.is_empty() && value.parse::<i64>().is_ok() {
+            Some(value.to_string())
+        } else {
+            None
+        }
+    }
+}
+
+// a line like `part_one = 2264607`
+fn parse_answer(line: &str) -> Result<(usize, String), String> {
+    let (key, value) = line.split_once('=').ok_or("expected key = value")?;
+
+    let part = match key.trim() {
+        "part_one" => 0,
+        "part_two" => 1,
+        k => return Err(format!("unknown key {}", k)),
+    };
+
+    let value = parse_value(value.trim()).ok_or("invalid value")?;
+
+    Ok((part, value))
+}
+
+fn format_value(value: &str) -> String {
+    if value.parse::<i64>().is_ok() {
+        return value.to_string();
+    }
+
+    let mut res = String::from('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            c => res.push(c),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+// what an example is expected to give and the parameters to solve it with
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Sidecar {
+    pub expected: [Option<String>; 2],
+    pub params: Overrides,
+}
+
+pub fn parse_sidecar(input: &str) -> Result<Sidecar, String> {
+    let mut res = Sidecar::default();
+    let mut in_params = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line == "[params]" {
+            in_params = true;
+            continue;
+        }
+
+        if !in_params {
+            let (part, value) = parse_answer(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+            res.expected[part] = Some(value);
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once('=')
+            .and_then(|(name, value)| Some((name.trim(), parse_value(value.trim())?)))
+            .ok_or(format!("line {}: expected name = value", i + 1))?;
+
+        res.params.set(name, &value);
+    }
+
+    Ok(res)
+}
+
+pub fn format_expected(expected: &[Option<String>; 2]) -> String {
+    let mut res = String::new();
+
+    for (key, value) in ["part_one", "part_two"].iter().zip(expected) {
+        if let Some(value) = value {
+            res += &format!("{} = {}\n", key, format_value(value));
+        }
+    }
+
+    res
+}
+
+impl AnswerStore {
+    pub fn parse(input: &str) -> Result<AnswerStore, String> {
+        let mut store = AnswerStore::default();
+        let mut day = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[') {
+                let d = section
+                    .strip_suffix(']')
+                    .and_then(|s| s.strip_prefix("day"))
+                    .and_then(|s| s.parse().ok())
+                    .ok_or(format!("line {}: expected a section like [day01]", i + 1))?;
+
+                day = Some(d);
+                continue;
+            }
+
+            let d = day.ok_or(format!("line {}: answer outside of a day section", i + 1))?;
+            let (part, value) = parse_answer(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+
+            store.days.entry(d).or_default()[part] = Some(value);
+        }
+
+        Ok(store)
+    }
+
+    // a missing file is an empty store
+    pub fn load(path: &Path) -> Result<AnswerStore, String> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => AnswerStore::parse(&s).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(_) => Err(format!("could not read file {}", path.display())),
+        }
+    }
+
+    pub fn expected(&self, day: u32, part: usize) -> Option<&str> {
+        self.days.get(&day)?[part].as_deref()
+    }
+
+    pub fn check(&self, day: u32, part: usize, answer: &Answer) -> Check {
+        let actual = answer.to_string();
+
+        match self.expected(day, part) {
+            None => Check::Missing,
+            Some(expected) if expected == actual => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static STORE: &str = r#"
+# answers for the real inputs
+[day01]
+part_one = 2264607
+part_two = "19457120" # trailing comment
+
+[day17]
+part_one = "1,5,0,3,7,3,0,3,1"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let store = AnswerStore::parse(STORE).unwrap();
+
+        assert_eq!(Some("2264607"), store.expected(1, 0));
+        assert_eq!(Some("19457120"), store.expected(1, 1));
+        assert_eq!(Some("1,5,0,3,7,3,0,3,1"), store.expected(17, 0));
+        assert_eq!(None, store.expected(17, 1));
+        assert_eq!(None, store.expected(2, 0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(AnswerStore::parse("part_one = 1").is_err());
+        assert!(AnswerStore::parse("[day01]\npart_three = 1").is_err());
+        assert!(AnswerStore::parse("[day01]\npart_one = abc").is_err());
+        assert!(AnswerStore::parse("[day01]\npart_one = \"abc").is_err());
+        assert!(AnswerStore::parse("[first]").is_err());
+    }
+
+    #[test]
+    fn test_expected() {
+        let expected = [Some("161".to_string()), Some("co,de\n\"ka\"".to_string())];
+        let text = format_expected(&expected);
+
+        assert_eq!("part_one = 161\npart_two = \"co,de\\n\\\"ka\\\"\"\n", text);
+        assert_eq!(Ok(expected), parse_sidecar(&text).map(|s| s.expected));
+        assert_eq!(Ok(Sidecar::default()), parse_sidecar("# nothing yet\n"));
+        assert!(parse_sidecar("[day01]\npart_one = 1").is_err());
+    }
+
+    #[test]
+    fn test_sidecar_params() {
+        let sidecar =
+            parse_sidecar("part_one = 12\n\n[params]\nwidth = 11\nname = \"a\"\n").unwrap();
+
+        let mut params = Overrides::default();
+        params.set("width", "11");
+        params.set("name", "a");
+
+        assert_eq!(
+            Sidecar {
+                expected: [Some("12".to_string()), None],
+                params
+            },
+            sidecar
+        );
+        assert!(parse_sidecar("[params]\nwidth").is_err());
+        assert!(parse_sidecar("[params]\npart_one = 1\n[day01]").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let store = AnswerStore::parse(STORE).unwrap();
+
+        assert_eq!(Check::Pass, store.check(1, 0, &Answer::Integer(2264607)));
+        assert_eq!(
+            Check::Fail {
+                expected: "19457120".to_string(),
+                actual: "1".to_string()
+            },
+            store.check(1, 1, &Answer::Integer(1))
+        );
+        assert_eq!(Check::Missing, store.check(17, 1, &Answer::Integer(1)));
+    }
+}
@@ -0,0 +1,110 @@
+// the examples of the puzzles in data/test/yearYYYY, a day with a single example has dayDD.txt
+// and a day with more of them numbers them like dayDD-1.txt
+//
+// every example may have a sidecar dayDD[-N].expected with its answers and parameters
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::parse_sidecar, Overrides};
+
+// the file of the only example of a day, also where new examples are written
+pub fn path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("year{}", year))
+        .join(format!("day{:02}.txt", day))
+}
+
+// the day of an example file, from names like day03.txt or day03-1.txt
+pub fn day(path: &Path) -> Option<u32> {
+    if path.extension()? != "txt" {
+        return None;
+    }
+
+    let stem = path.file_stem()?.to_str()?.strip_prefix("day")?;
+
+    // the number is what tells apart the examples of a day, it cannot be empty
+    let day = match stem.split_once('-') {
+        Some((day, number)) if !number.is_empty() => day,
+        Some(_) => return None,
+        None => stem,
+    };
+
+    if day.len() != 2 {
+        return None;
+    }
+
+    day.parse().ok()
+}
+
+// every example of the day in the order of their names
+pub fn find(dir: &Path, year: u32, day: u32) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir.join(format!("year{}", year))) else {
+        return Vec::new();
+    };
+
+    let mut examples: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| self::day(path) == Some(day))
+        .collect();
+
+    examples.sort();
+    examples
+}
+
+// the parameters in the sidecar of an example, an example without a sidecar needs none
+pub fn params(example: &Path) -> Result<Overrides, String> {
+    let sidecar = example.with_extension("expected");
+
+    match fs::read_to_string(&sidecar) {
+        Ok(s) => parse_sidecar(&s)
+            .map(|sidecar| sidecar.params)
+            .map_err(|e| format!("{}: {}", sidecar.display(), e)),
+        Err(_) => Ok(Overrides::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day() {
+        assert_eq!(Some(3), day(Path::new("data/test/year2024/day03.txt")));
+        assert_eq!(Some(17), day(Path::new("day17-2.txt")));
+        assert_eq!(None, day(Path::new("day17-2.expected")));
+        assert_eq!(None, day(Path::new("day17-.txt")));
+        assert_eq!(None, day(Path::new("day3.txt")));
+        assert_eq!(None, day(Path::new("notes.txt")));
+    }
+
+    #[test]
+    fn test_find() {
+        let dir = Path::new("data/test");
+
+        assert_eq!(vec![path(dir, 2024, 1)], find(dir, 2024, 1));
+        assert_eq!(
+            vec![
+                dir.join("year2024/day17-1.txt"),
+                dir.join("year2024/day17-2.txt")
+            ],
+            find(dir, 2024, 17)
+        );
+        assert!(find(dir, 2024, 26).is_empty());
+        assert!(find(dir, 1999, 1).is_empty());
+    }
+
+    #[test]
+    fn test_params() {
+        let dir = Path::new("data/test");
+        let mut expected = Overrides::default();
+        expected.set("width", "11");
+        expected.set("height", "7");
+
+        assert_eq!(Ok(expected), params(&path(dir, 2024, 14)));
+        assert_eq!(Ok(Overrides::default()), params(&path(dir, 2024, 1)));
+        assert_eq!(Ok(Overrides::default()), params(Path::new("missing.txt")));
+    }
+}
@@ -0,0 +1,194 @@
+// pulls the examples and the answers to them out of a saved puzzle page
+//
+// every part of a puzzle is an <article>, the example is its first <pre><code> block and the
+// answer for the example is the last emphasized code, as in "adds up to <code><em>161</em></code>"
+// part two reuses the example of part one unless its article brings its own
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub expected: [Option<String>; 2],
+}
+
+fn decode(html: &str) -> String {
+    let mut res = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(i) = rest.find(['<', '&']) {
+        res += &rest[..i];
+        rest = &rest[i..];
+
+        if rest.starts_with('<') {
+            // tags inside of examples only change the style
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let entity = rest.find(';').map_or(rest, |end| &rest[..=end]);
+
+        let c = match entity {
+            "&lt;" => Some('<'),
+            "&gt;" => Some('>'),
+            "&amp;" => Some('&'),
+            "&quot;" => Some('"'),
+            "&apos;" | "&#39;" => Some('\''),
+            _ => entity
+                .strip_prefix("&#")
+                .and_then(|n| n.strip_suffix(';'))
+                .and_then(|n| match n.strip_prefix('x') {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => n.parse().ok(),
+                })
+                .and_then(char::from_u32),
+        };
+
+        match c {
+            Some(c) => {
+                res.push(c);
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    res + rest
+}
+
+// the contents of every `open ... close` in `html`
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut res = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+
+        res.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    res
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    between(html, "<article", "</article>")
+}
+
+fn example(article: &str) -> Option<String> {
+    between(article, "<pre><code>", "</code></pre>")
+        .first()
+        .map(|block| decode(block))
+}
+
+fn answer(article: &str) -> Option<String> {
+    // answers are written as both <code><em> and <em><code>
+    let article = article
+        .replace("<em><code>", "<code><em>")
+        .replace("</code></em>", "</em></code>");
+
+    between(&article, "<code><em>", "</em></code>")
+        .last()
+        .map(|a| decode(a).trim().to_string())
+}
+
+pub fn extract(html: &str) -> Result<Vec<Example>, String> {
+    let articles = articles(html);
+
+    let part_one = articles.first().ok_or("no puzzle description found")?;
+
+    let mut examples = vec![Example {
+        input: example(part_one).ok_or("no example found in part one")?,
+        expected: [answer(part_one), None],
+    }];
+
+    if let Some(part_two) = articles.get(1) {
+        match example(part_two) {
+            Some(input) => examples.push(Example {
+                input,
+                expected: [None, answer(part_two)],
+            }),
+            None => examples[0].expected[1] = answer(part_two),
+        }
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)</em><em>mul(8,5)</em>)</code></pre>
+<p>Only the four highlighted sections are real <code>mul</code> instructions. Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>178538786</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For example:</p>
+<pre><code>xmul(2,4)&amp;mul[3,7]!^<em>don't()</em>_mul(5,5)+mul(32,64](mul(11,8)un<em>do()</em>?mul(8,5))</code></pre>
+<p>This time, the sum of the results is <em><code>48</code></em> (<code>2*4 + 8*5</code>).</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            "a < b && \"c\" 'd' é",
+            decode("a &lt; <em>b</em> &amp;&amp; &quot;c&quot; &#39;d&#39; &#xe9;")
+        );
+        assert_eq!("R&D", decode("R&D"));
+    }
+
+    #[test]
+    fn test_separate_examples() {
+        let examples = extract(PAGE).unwrap();
+
+        assert_eq!(
+            vec![
+                Example {
+                    input:
+                        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+                            .to_string(),
+                    expected: [Some("161".to_string()), None],
+                },
+                Example {
+                    input:
+                        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+                            .to_string(),
+                    expected: [None, Some("48".to_string())],
+                }
+            ],
+            examples
+        );
+    }
+
+    #[test]
+    fn test_shared_example() {
+        let page = r#"<article><pre><code>3   4
+4   3
+</code></pre><p>total distance of <code><em>11</em></code>!</p></article>
+<article><p>similarity score is <code><em>31</em></code>.</p></article>"#;
+
+        assert_eq!(
+            vec![Example {
+                input: "3   4\n4   3\n".to_string(),
+                expected: [Some("11".to_string()), Some("31".to_string())],
+            }],
+            extract(page).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_missing_parts() {
+        assert!(extract("<html></html>").is_err());
+        assert!(extract("<article><p>no example</p></article>").is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// inputs saved by browsers and editors differ in details that the parsers do not expect, so every
+// input is brought into the same shape before it is parsed: no byte order mark, lf line endings,
+// no whitespace at the end of a line and exactly one newline at the end
+
+use std::fmt;
+
+// what `normalize` had to change, a missing final newline is not counted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub bom: bool,
+    pub line_endings: bool,
+    pub trailing_whitespace: bool,
+}
+
+impl Changes {
+    pub fn any(&self) -> bool {
+        self.bom || self.line_endings || self.trailing_whitespace
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let changes: Vec<&str> = [
+            (self.bom, "removed the byte order mark"),
+            (self.line_endings, "converted crlf line endings"),
+            (self.trailing_whitespace, "removed trailing whitespace"),
+        ]
+        .into_iter()
+        .filter_map(|(changed, description)| changed.then_some(description))
+        .collect();
+
+        write!(f, "{}", changes.join(", "))
+    }
+}
+
+pub fn normalize(input: &str) -> (String, Changes) {
+    let mut changes = Changes::default();
+
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.bom = true;
+            rest
+        }
+        None => input,
+    };
+
+    let mut res = String::with_capacity(input.len() + 1);
+
+    for line in input.split('\n') {
+        let without_cr = line.strip_suffix('\r').unwrap_or(line);
+        let trimmed = without_cr.trim_end();
+
+        changes.line_endings |= without_cr.len() != line.len();
+        changes.trailing_whitespace |= trimmed.len() != without_cr.len();
+
+        res += trimmed;
+        res.push('\n');
+    }
+
+    // every line got a newline, including the empty one after the final newline, so more than
+    // two newlines at the end mean that there were empty lines at the end of the input
+    let content = res.trim_end_matches('\n').len();
+
+    if res.len() - content > 2 || (content == 0 && !input.is_empty()) {
+        changes.trailing_whitespace = true;
+    }
+
+    res.truncate(content);
+
+    if !res.is_empty() {
+        res.push('\n');
+    }
+
+    (res, changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged() {
+        assert_eq!(
+            ("1   2\n3   4\n".to_string(), Changes::default()),
+            normalize("1   2\n3   4\n")
+        );
+        assert_eq!(("".to_string(), Changes::default()), normalize(""));
+    }
+
+    #[test]
+    fn test_missing_newline() {
+        let (input, changes) = normalize("125 17");
+
+        assert_eq!("125 17\n", input);
+        assert!(!changes.any());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (input, changes) = normalize("\u{feff}47|53\r\n97|13 \r\n\r\n75,47\t\r\n\r\n\r\n");
+
+        assert_eq!("47|53\n97|13\n\n75,47\n", input);
+        assert_eq!(
+            Changes {
+                bom: true,
+                line_endings: true,
+                trailing_whitespace: true,
+            },
+            changes
+        );
+        assert_eq!(
+            "removed the byte order mark, converted crlf line endings, removed trailing whitespace",
+            changes.to_string()
+        );
+    }
+
+    #[test]
+    fn test_trailing_empty_lines() {
+        let (input, changes) = normalize("1\n2\n\n\n");
+
+        assert_eq!("1\n2\n", input);
+        assert_eq!(
+            Changes {
+                trailing_whitespace: true,
+                ..Changes::default()
+            },
+            changes
+        );
+    }
+}
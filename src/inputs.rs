@@ -0,0 +1,484 @@
+// puzzle inputs live in a local cache below data/input, days that are missing there are
+// fetched once and stored, after that the cached file is always used
+//
+// servers are asked for one input at a time with a pause in between, and a day that could not
+// be downloaded is not asked for again until an hour has passed
+
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+const FETCH_INTERVAL: Duration = Duration::from_secs(3);
+const RETRY_AFTER: Duration = Duration::from_secs(60 * 60);
+
+// a source for the inputs that are not cached yet
+pub trait Fetch: fmt::Debug + Send + Sync {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String>;
+
+    // whether the inputs come from a server, which is asked politely
+    fn remote(&self) -> bool {
+        false
+    }
+}
+
+fn day_file(year: u32, day: u32) -> PathBuf {
+    Path::new(&format!("year{}", year)).join(format!("day{:02}.txt", day))
+}
+
+// copies the inputs from another directory laid out like data/input
+#[derive(Debug)]
+pub struct DirFetcher {
+    pub dir: PathBuf,
+}
+
+impl Fetch for DirFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+        let path = self.dir.join(day_file(year, day));
+
+        fs::read_to_string(&path).or(Err(format!("could not read file {}", path.display())))
+    }
+}
+
+// downloads the inputs with a session token, from `{base}/{year}/day/{day}/input`
+pub struct HttpFetcher {
+    pub base: String,
+    pub session: String,
+    // an email address or url in the user agent, so that the server knows who to contact
+    pub contact: String,
+}
+
+// keep the session token out of any output
+impl fmt::Debug for HttpFetcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpFetcher")
+            .field("base", &self.base)
+            .field("contact", &self.contact)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base.trim_end_matches('/'),
+            year,
+            day
+        );
+
+        if let Some(rest) = url.strip_prefix("http://") {
+            self.get_plain(rest)
+        } else if url.starts_with("https://") {
+            self.get_curl(&url)
+        } else {
+            Err(format!("unsupported url {}", url))
+        }
+    }
+
+    fn remote(&self) -> bool {
+        true
+    }
+}
+
+impl HttpFetcher {
+    fn user_agent(&self) -> String {
+        format!(
+            "rust_aoc/{} (input cache; {})",
+            env!("CARGO_PKG_VERSION"),
+            self.contact
+        )
+    }
+
+    // std has no tls, so plain http is only meant for local servers
+    fn get_plain(&self, url: &str) -> Result<String, String> {
+        let (host, path) = match url.find('/') {
+            Some(i) => (&url[..i], &url[i..]),
+            None => (url, "/"),
+        };
+
+        let mut stream =
+            TcpStream::connect(host).or(Err(format!("could not connect to {}", host)))?;
+
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n\r\n",
+            path,
+            host,
+            self.user_agent(),
+            self.session
+        )
+        .or(Err(format!("could not send request to {}", host)))?;
+
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .or(Err(format!("could not read response from {}", host)))?;
+
+        parse_response(&String::from_utf8_lossy(&response))
+    }
+
+    // std has no tls, so https is downloaded with the curl on the PATH
+    // the session is handed to curl on stdin so that it does not show up in the process list
+    fn get_curl(&self, url: &str) -> Result<String, String> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--header", "@-"])
+            .args(["--user-agent", &self.user_agent(), url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => "downloading from https needs curl".to_string(),
+                _ => "could not run curl".to_string(),
+            })?;
+
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(format!("Cookie: session={}\n", self.session).as_bytes())
+            .or(Err("could not pass the session to curl".to_string()))?;
+
+        let output = child
+            .wait_with_output()
+            .or(Err("could not run curl".to_string()))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "could not download {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        String::from_utf8(output.stdout).or(Err(format!("{} is not valid utf-8", url)))
+    }
+}
+
+fn parse_response(response: &str) -> Result<String, String> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("malformed http response")?;
+
+    let mut lines = head.lines();
+    let status = lines.next().unwrap_or_default();
+
+    if status.split(' ').nth(1) != Some("200") {
+        return Err(format!("server answered {}", status));
+    }
+
+    let chunked = lines.any(|l| {
+        l.to_ascii_lowercase()
+            .starts_with("transfer-encoding: chunked")
+    });
+
+    if !chunked {
+        return Ok(body.to_string());
+    }
+
+    let mut res = String::new();
+    let mut rest = body;
+
+    loop {
+        let (size, data) = rest.split_once("\r\n").ok_or("malformed chunk")?;
+        let size = usize::from_str_radix(size.trim(), 16).or(Err("malformed chunk size"))?;
+
+        if size == 0 {
+            return Ok(res);
+        }
+
+        res += data.get(..size).ok_or("truncated chunk")?;
+        rest = data[size..].trim_start_matches("\r\n");
+    }
+}
+
+#[derive(Debug)]
+pub struct InputCache {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetch>>,
+    // when the server was last asked, held while fetching so that days are fetched one by one
+    last_fetch: Mutex<Option<Instant>>,
+}
+
+impl InputCache {
+    pub fn new(dir: PathBuf, fetcher: Option<Box<dyn Fetch>>) -> Self {
+        InputCache {
+            dir,
+            fetcher,
+            last_fetch: Mutex::new(None),
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(day_file(year, day))
+    }
+
+    // remembers why a day could not be fetched
+    fn failure_path(&self, year: u32, day: u32) -> PathBuf {
+        self.path(year, day).with_extension("txt.failed")
+    }
+
+    // the failure of a recent fetch of the day, while it is too early to ask again
+    fn recent_failure(&self, year: u32, day: u32) -> Option<String> {
+        let path = self.failure_path(year, day);
+        let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+
+        if SystemTime::now().duration_since(modified).ok()? >= RETRY_AFTER {
+            return None;
+        }
+
+        let error = fs::read_to_string(&path).ok()?;
+
+        Some(format!(
+            "{}, not fetched again within an hour unless {} is removed",
+            error.trim_end(),
+            path.display()
+        ))
+    }
+
+    fn fetch(&self, fetcher: &dyn Fetch, year: u32, day: u32) -> Result<String, String> {
+        if !fetcher.remote() {
+            return fetcher.fetch(year, day);
+        }
+
+        if let Some(error) = self.recent_failure(year, day) {
+            return Err(error);
+        }
+
+        let mut last_fetch = self.last_fetch.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(wait) = last_fetch.and_then(|last| FETCH_INTERVAL.checked_sub(last.elapsed())) {
+            thread::sleep(wait);
+        }
+
+        let res = fetcher.fetch(year, day);
+        *last_fetch = Some(Instant::now());
+
+        let failure = self.failure_path(year, day);
+
+        match &res {
+            Ok(_) => {
+                let _ = fs::remove_file(&failure);
+            }
+            Err(e) => {
+                let _ = store(&failure, e);
+            }
+        }
+
+        res
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<String, String> {
+        let path = self.path(year, day);
+
+        // the new command leaves empty input files behind, those still need to be fetched
+        if let Ok(input) = fs::read_to_string(&path) {
+            if !input.is_empty() {
+                return Ok(input);
+            }
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            return Err(format!("could not read file {}", path.display()));
+        };
+
+        let input = self.fetch(fetcher.as_ref(), year, day)?;
+        store(&path, &input)?;
+
+        Ok(input)
+    }
+}
+
+// inputs are personal, so only the owner may read them
+// the file is written next to its final place first, so a failed write never looks cached
+fn store(path: &Path, input: &str) -> Result<(), String> {
+    let error = || format!("could not write file {}", path.display());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|_| error())?;
+    }
+
+    let partial = path.with_extension("txt.partial");
+    let mut options = OpenOptions::new();
+
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(&partial)
+        .and_then(|mut file| file.write_all(input.as_bytes()))
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|_| error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    #[derive(Debug, Default)]
+    struct CountingFetcher {
+        calls: Arc<AtomicUsize>,
+        // a server that does not have the inputs of day 25 yet
+        remote: bool,
+    }
+
+    impl Fetch for CountingFetcher {
+        fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+
+            match day {
+                25 => Err("server answered HTTP/1.1 404 Not Found".to_string()),
+                _ => Ok(format!("{} {}\n", year, day)),
+            }
+        }
+
+        fn remote(&self) -> bool {
+            self.remote
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rust_aoc_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_fetches_only_once() {
+        let dir = temp_dir("cache");
+        let fetcher = CountingFetcher::default();
+        let calls = fetcher.calls.clone();
+
+        let cache = InputCache::new(dir.clone(), Some(Box::new(fetcher)));
+
+        assert_eq!(Ok("2024 3\n".to_string()), cache.get(2024, 3));
+        assert_eq!(Ok("2024 3\n".to_string()), cache.get(2024, 3));
+        assert_eq!(1, calls.load(Ordering::Relaxed));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = fs::metadata(cache.path(2024, 3))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(0o600, mode & 0o777);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_remote_fetches() {
+        let dir = temp_dir("remote");
+        let fetcher = CountingFetcher {
+            remote: true,
+            ..Default::default()
+        };
+        let calls = fetcher.calls.clone();
+
+        let cache = InputCache::new(dir.clone(), Some(Box::new(fetcher)));
+
+        // the failure is remembered instead of asking the server again
+        assert!(cache.get(2024, 25).is_err());
+        assert!(cache.get(2024, 25).unwrap_err().contains("404 Not Found"));
+        assert_eq!(1, calls.load(Ordering::Relaxed));
+
+        // the next day waits for the server
+        let start = Instant::now();
+        assert_eq!(Ok("2024 24\n".to_string()), cache.get(2024, 24));
+        assert!(start.elapsed() >= FETCH_INTERVAL / 2);
+        assert_eq!(2, calls.load(Ordering::Relaxed));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dir_fetcher() {
+        let dir = temp_dir("mirror");
+        store(&dir.join("year2024/day05.txt"), "47|53\n").unwrap();
+
+        let cache = InputCache::new(
+            dir.join("cache"),
+            Some(Box::new(DirFetcher { dir: dir.clone() })),
+        );
+
+        assert_eq!(Ok("47|53\n".to_string()), cache.get(2024, 5));
+        assert!(cache.get(2024, 6).is_err());
+        assert!(cache.path(2024, 5).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_http_fetcher() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+
+            // the request may arrive in several writes, it ends with an empty line
+            while !request.ends_with("\r\n\r\n") {
+                if reader.read_line(&mut request).unwrap() == 0 {
+                    break;
+                }
+            }
+
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1 2\n\r\n0\r\n\r\n",
+                )
+                .unwrap();
+
+            request
+        });
+
+        let fetcher = HttpFetcher {
+            base: format!("http://{}/", address),
+            session: "53cr37".to_string(),
+            contact: "me@example.com".to_string(),
+        };
+
+        assert_eq!(Ok("1 2\n".to_string()), fetcher.fetch(2024, 7));
+
+        let request = server.join().unwrap();
+
+        assert!(request.starts_with("GET /2024/day/7/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=53cr37\r\n"));
+        assert!(request.contains("User-Agent: rust_aoc/0.1.0 (input cache; me@example.com)\r\n"));
+        assert!(!format!("{:?}", fetcher).contains("53cr37"));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Ok("1 2\n".to_string()),
+            parse_response("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1 2\n")
+        );
+        assert_eq!(
+            Err("server answered HTTP/1.1 404 Not Found".to_string()),
+            parse_response("HTTP/1.1 404 Not Found\r\n\r\n")
+        );
+    }
+}
@@ -1,5 +1,13 @@
-use std::path::{Path, PathBuf};
-
+pub mod answer;
+pub mod answers;
+pub mod examples;
+pub mod extract;
+pub mod input;
+pub mod memory;
+pub mod report;
+pub mod runner;
+pub mod solution;
+pub mod trace;
 pub mod year2024;
 pub mod year2025;
 
@@ -8,3 +16,10 @@ pub mod util {
     pub mod parse;
     pub mod point;
 }
+
+pub use answer::Answer;
+pub use solution::{find, solve, solvers, Error, Overrides, Solution, Solver, Timings};
+pub use util::parse::ParseError;
+
+// the solvers of every src/yearYYYY/dayDD.rs, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
@@ -1,14 +1,43 @@
-use std::{path::PathBuf, str::FromStr, thread::available_parallelism};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Read,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
-use rust_aoc::year2024;
-use rust_aoc::year2025;
+mod inputs;
+mod scaffold;
+mod serve;
+mod watch;
+
+use inputs::{DirFetcher, Fetch, HttpFetcher, InputCache};
+use rust_aoc::{
+    answers::{format_expected, AnswerStore, Check},
+    examples, input, memory,
+    report::{self, Failure, Report},
+    runner,
+    trace::{self, Level},
+    Answer, Overrides, Timings,
+};
+
+// counts the heap memory of every thread for --memory, only in builds with the memory feature
+// as counting slows down every allocation
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum TestOrRun {
     Test,
     Run,
+    Verify,
     Exec,
-    Unrecognized,
+    New,
+    Extract,
+    Serve,
+    Watch,
 }
 
 impl FromStr for TestOrRun {
@@ -18,121 +47,1007 @@ impl FromStr for TestOrRun {
         match s {
             "test" => Ok(Self::Test),
             "run" => Ok(Self::Run),
+            "verify" => Ok(Self::Verify),
+            "exec" => Ok(Self::Exec),
+            "new" => Ok(Self::New),
+            "extract" => Ok(Self::Extract),
+            "serve" => Ok(Self::Serve),
+            "watch" => Ok(Self::Watch),
             _ => Err("option not recognized"),
         }
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err("format not recognized"),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct RunConfig {
     command: TestOrRun,
-    year: u32,
+    years: Vec<u32>,
+    // no days selects every day of the years, the ones not implemented are counted as such
     days: Vec<u32>,
+    // read the input from this file instead of the data directory, "-" reads stdin
     filename: Option<String>,
+    // use this text as the input instead of reading a file
+    text: Option<String>,
+    data_dir: PathBuf,
+    // the real inputs, fetched into data/input when they are missing
+    inputs: InputCache,
+    jobs: usize,
+    // print a table of parse and solve times, flagging days slower than the budget
+    time: bool,
+    budget: Duration,
+    format: Format,
+    // stop at the first day that fails instead of solving the remaining days
+    fail_fast: bool,
+    // tell when an input had to be normalized before it could be parsed
+    warn_input: bool,
+    // parameters of the solver, for inputs like the examples that need other values
+    params: Overrides,
+    // the port serve listens on
+    port: u16,
+    // the page that may post to serve from a browser
+    origin: Option<String>,
+    // give up on a day that takes longer than this
+    timeout: Option<Duration>,
+    // measure the peak heap memory of every day
+    memory: bool,
+    // the debug output of the solvers, RUST_AOC_LOG decides when not given
+    log_level: Option<Level>,
 }
 
 impl RunConfig {
     pub fn parse(mut args: impl Iterator<Item = String>) -> Result<RunConfig, &'static str> {
         args.next();
 
-        let command = match args.next() {
+        let mut filename = None;
+        let mut text = None;
+        let mut data_dir = PathBuf::from("data/");
+        let mut fetch = None;
+        let mut jobs = None;
+        let mut time = false;
+        let mut budget = Duration::from_secs(1);
+        let mut format = Format::Text;
+        let mut fail_fast = false;
+        let mut warn_input = false;
+        let mut params = Overrides::default();
+        let mut port = 8080;
+        let mut origin = None;
+        let mut timeout = None;
+        let mut memory = false;
+        let mut log_level = None;
+        let mut positional = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => filename = Some(args.next().ok_or("no input file provided")?),
+                "--string" => text = Some(args.next().ok_or("no input string provided")?),
+                "--data-dir" => {
+                    data_dir = PathBuf::from(args.next().ok_or("no data directory provided")?)
+                }
+                "--fetch" => fetch = Some(args.next().ok_or("no input source provided")?),
+                "--jobs" => {
+                    jobs = match args.next().map(|s| s.parse()) {
+                        Some(Ok(n)) if n > 0 => Some(n),
+                        Some(_) => return Err("number of jobs must be a positive integer"),
+                        None => return Err("no number of jobs provided"),
+                    }
+                }
+                "--time" => time = true,
+                "--budget" => {
+                    budget = match args.next() {
+                        Some(s) => parse_duration(&s).ok_or("could not parse budget")?,
+                        None => return Err("no budget provided"),
+                    }
+                }
+                "--format" => format = Format::from_str(&args.next().ok_or("no format provided")?)?,
+                "--fail-fast" => fail_fast = true,
+                "--warn-input" => warn_input = true,
+                "--param" => {
+                    let param = args.next().ok_or("no parameter provided")?;
+                    let (name, value) = param
+                        .split_once('=')
+                        .ok_or("parameters are given as name=value")?;
+
+                    params.set(name, value);
+                }
+                "--timeout" => {
+                    timeout = match args.next() {
+                        Some(s) => Some(parse_duration(&s).ok_or("could not parse timeout")?),
+                        None => return Err("no timeout provided"),
+                    }
+                }
+                "--memory" if cfg!(feature = "memory") => memory = true,
+                "--memory" => return Err("--memory needs a build with --features memory"),
+                // given twice it also prints the large output, like whole grids
+                "--verbose" => {
+                    log_level = match log_level {
+                        None => Some(Level::Debug),
+                        Some(_) => Some(Level::Trace),
+                    }
+                }
+                "--port" => {
+                    port = match args.next() {
+                        Some(s) => s.parse().or(Err("could not parse port"))?,
+                        None => return Err("no port provided"),
+                    }
+                }
+                "--origin" => origin = Some(args.next().ok_or("no origin provided")?),
+                s if s.starts_with("--") => return Err("option not recognized"),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+
+        let command = match positional.next() {
             Some(s) => TestOrRun::from_str(&s)?,
             None => return Err("no command provided"),
         };
 
-        let year = match args.next() {
-            Some(s) => s.parse().or(Err("could not parse year"))?,
+        let years = match positional.next() {
+            Some(s) => parse_years(&s)?,
+            // serve takes the year from the path of every request
+            None if command == TestOrRun::Serve => Vec::new(),
             None => return Err("no year provided"),
         };
 
-        let mut days = Vec::new();
+        let mut days = BTreeSet::new();
 
         // consume the remaining iterator
-        for s in args {
-            let day = s.parse().or(Err("could not parse day"))?;
+        for s in positional {
+            parse_days(&s, &mut days)?;
+        }
 
-            if day > 25 {
-                return Err("day is outside of the allowed range [0, 25]");
-            }
+        let days: Vec<u32> = days.into_iter().collect();
 
-            days.push(day);
+        if filename.is_some() && text.is_some() {
+            return Err("only one of an input file and an input string can be given");
+        }
+
+        if (filename.is_some() || text.is_some()) && (years.len() != 1 || days.len() != 1) {
+            return Err("an input file or string can only be given for a single day");
+        }
+
+        if !params.is_empty() && (years.len() != 1 || days.len() != 1) {
+            return Err("parameters can only be given for a single day");
+        }
+
+        if command == TestOrRun::New && days.is_empty() {
+            return Err("new needs the days to create");
+        }
+
+        if command == TestOrRun::Extract && (filename.is_none() || filename.as_deref() == Some("-"))
+        {
+            return Err("extract needs the saved puzzle page as the input file");
+        }
+
+        if command == TestOrRun::Watch && (years.len() != 1 || days.len() != 1) {
+            return Err("watch needs a single day");
+        }
+
+        if command == TestOrRun::Exec && filename.is_none() && text.is_none() {
+            return Err("exec needs an input file or an input string");
         }
 
+        let inputs = InputCache::new(
+            data_dir.join("input"),
+            fetcher(
+                fetch,
+                std::env::var("AOC_SESSION").ok(),
+                std::env::var("AOC_CONTACT").ok(),
+            )?,
+        );
+
+        // exec always reports how long the day took, the memory is shown in the same table
+        let time = time || memory || command == TestOrRun::Exec;
+
+        // days solved side by side slow each other down, so timed runs solve one at a time
+        let jobs = match jobs {
+            Some(jobs) => jobs,
+            None if time => 1,
+            None => runner::default_jobs(),
+        };
+
         Ok(RunConfig {
             command,
-            year,
+            years,
             days,
-            filename: None,
+            filename,
+            text,
+            data_dir,
+            inputs,
+            jobs,
+            time,
+            budget,
+            format,
+            fail_fast,
+            warn_input,
+            params,
+            port,
+            origin,
+            timeout,
+            memory,
+            log_level,
         })
     }
 }
 
+// urls are downloaded from with the session token, "aoc" is short for adventofcode.com,
+// anything else is a directory to copy from; nothing is fetched without --fetch
+fn fetcher(
+    source: Option<String>,
+    session: Option<String>,
+    contact: Option<String>,
+) -> Result<Option<Box<dyn Fetch>>, &'static str> {
+    let Some(source) = source else {
+        return Ok(None);
+    };
+
+    let base = match source.as_str() {
+        "aoc" => "https://adventofcode.com".to_string(),
+        s if s.starts_with("http://") || s.starts_with("https://") => source,
+        _ => return Ok(Some(Box::new(DirFetcher { dir: source.into() }))),
+    };
+
+    Ok(Some(Box::new(HttpFetcher {
+        base,
+        session: session.ok_or("AOC_SESSION must hold a session token to download inputs")?,
+        contact: contact
+            .ok_or("AOC_CONTACT must hold an email address or url to download inputs")?,
+    })))
+}
+
+// a day of a year, as (year, day)
+type PuzzleDay = (u32, u32);
+
+impl RunConfig {
+    // every selected day in order; a year without days selects all of them, so that the missing
+    // ones are counted as well
+    fn selected(&self) -> Vec<PuzzleDay> {
+        let days = match self.days.is_empty() {
+            true => (1..=25).collect(),
+            false => self.days.clone(),
+        };
+
+        self.years
+            .iter()
+            .flat_map(|&year| days.iter().map(move |&day| (year, day)))
+            .collect()
+    }
+
+    // the selected days split into the implemented ones and the rest
+    fn selection(&self) -> (Vec<PuzzleDay>, Vec<PuzzleDay>) {
+        self.selected()
+            .into_iter()
+            .partition(|&(year, day)| rust_aoc::find(year, day).is_some())
+    }
+
+    // the test command solves a day on every one of its examples, the others solve it once
+    fn runs(&self, days: &[PuzzleDay]) -> Vec<(PuzzleDay, Option<PathBuf>)> {
+        if self.command != TestOrRun::Test || self.filename.is_some() || self.text.is_some() {
+            return days.iter().map(|&day| (day, None)).collect();
+        }
+
+        let dir = self.data_dir.join("test");
+
+        days.iter()
+            .flat_map(|&(year, day)| {
+                let mut found = examples::find(&dir, year, day);
+
+                // a day without examples fails on the file it is missing
+                if found.is_empty() {
+                    found.push(examples::path(&dir, year, day));
+                }
+
+                found.into_iter().map(move |path| ((year, day), Some(path)))
+            })
+            .collect()
+    }
+}
+
+// accepts "all" for every implemented year, or a list of years like "2024,2025"
+fn parse_years(s: &str) -> Result<Vec<u32>, &'static str> {
+    if s == "all" {
+        let years: BTreeSet<u32> = rust_aoc::solvers().map(|s| s.year).collect();
+        return Ok(years.into_iter().collect());
+    }
+
+    let years: BTreeSet<u32> = s
+        .split(',')
+        .map(|y| y.parse().or(Err("could not parse year")))
+        .collect::<Result<_, _>>()?;
+
+    Ok(years.into_iter().collect())
+}
+
+fn parse_day(s: &str) -> Result<u32, &'static str> {
+    let day = s.parse().or(Err("could not parse day"))?;
+
+    if !(1..=25).contains(&day) {
+        return Err("day is outside of the allowed range [1, 25]");
+    }
+
+    Ok(day)
+}
+
+// accepts lists of days and inclusive ranges like "1-10,15", "20.." or "..5"
+fn parse_days(s: &str, days: &mut BTreeSet<u32>) -> Result<(), &'static str> {
+    for part in s.split(',') {
+        let (first, last) = if let Some((a, b)) = part.split_once("..") {
+            let b = b.strip_prefix('=').unwrap_or(b);
+            (
+                if a.is_empty() { 1 } else { parse_day(a)? },
+                if b.is_empty() { 25 } else { parse_day(b)? },
+            )
+        } else if let Some((a, b)) = part.split_once('-') {
+            (parse_day(a)?, parse_day(b)?)
+        } else {
+            let day = parse_day(part)?;
+            (day, day)
+        };
+
+        if first > last {
+            return Err("day range is empty");
+        }
+
+        days.extend(first..=last);
+    }
+
+    Ok(())
+}
+
+// lists days grouped by year, with runs of days shortened to ranges like "2025: 2-25"
+fn format_days(days: &[PuzzleDay]) -> String {
+    let mut groups: Vec<(u32, Vec<(u32, u32)>)> = Vec::new();
+
+    for &(year, day) in days {
+        match groups.last_mut() {
+            Some((y, ranges)) if *y == year => match ranges.last_mut() {
+                Some((_, last)) if *last + 1 == day => *last = day,
+                _ => ranges.push((day, day)),
+            },
+            _ => groups.push((year, vec![(day, day)])),
+        }
+    }
+
+    groups
+        .iter()
+        .map(|(year, ranges)| {
+            let ranges: Vec<String> = ranges
+                .iter()
+                .map(|&(first, last)| {
+                    if first == last {
+                        first.to_string()
+                    } else {
+                        format!("{}-{}", first, last)
+                    }
+                })
+                .collect();
+
+            format!("{}: {}", year, ranges.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// accepts durations like "1s", "250ms" or plain seconds like "0.5"
+fn parse_duration(s: &str) -> Option<Duration> {
+    let seconds: f64 = if let Some(ms) = s.strip_suffix("ms") {
+        ms.parse::<f64>().ok()? / 1000.0
+    } else {
+        s.strip_suffix('s').unwrap_or(s).parse().ok()?
+    };
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
 fn print_usage() {
-    println!("  Usage: rust_aoc [run|test] [year:20XX] [day: YY]");
+    println!("  Usage: rust_aoc [run|test|verify] [years] [days...]");
+    println!("         rust_aoc exec [year:20XX] [day: YY] [--input <path> | --string <text>]");
+    println!("         rust_aoc new [years] [days...]");
+    println!("         rust_aoc extract [year:20XX] [day: YY] --input <saved puzzle page>");
+    println!("         rust_aoc serve [--port <n>] [--origin <url>]");
+    println!("         rust_aoc watch [year:20XX] [day: YY]");
+    println!("  Years are a list like 2024,2025 or all, days are lists and inclusive ranges");
+    println!("  like 1-10,15 or 20.. and default to every day 1-25");
+    println!("  Options:");
+    println!("    --input <path>     read the input of a single day from <path>, - reads stdin");
+    println!("    --string <text>    use <text> as the input of a single day, as it is given; a");
+    println!("                       shell puts line breaks in with quotes or $'1\\n2'");
+    println!("    --data-dir <path>  look up inputs below <path> instead of data/");
+    println!("    --fetch <source>   fetch missing inputs from a directory, a url or aoc for");
+    println!("                       adventofcode.com; urls need the session token in AOC_SESSION");
+    println!("                       and an email address or url in AOC_CONTACT for the user");
+    println!("                       agent, https downloads need curl; days are downloaded one");
+    println!("                       at a time and failed ones are not retried for an hour");
+    println!(
+        "    --jobs <n>         solve up to <n> days in parallel, --time solves one at a time"
+    );
+    println!("                       unless a number of jobs is given");
+    println!("    --time             print parse and solve times for every day");
+    println!("    --budget <time>    flag days slower than <time> in the timing table, e.g. 1s");
+    println!("    --format <format>  print results as text, json (one object per line) or csv");
+    println!("    --fail-fast        stop at the first day that fails");
+    println!("    --param <n>=<v>    set the parameter <n> of the solver to <v>, e.g. width=11");
+    println!("    --warn-input       warn when an input has a byte order mark, crlf line endings");
+    println!("                       or trailing whitespace, which are removed before parsing");
+    println!("    --timeout <time>   give up on days that take longer than <time>, e.g. 10s; the");
+    println!("                       day keeps running in the background until the run ends,");
+    println!("                       which slows down the days solved after it");
+    println!("                       serve gives up after 10s unless a timeout is given");
+    println!("    --memory           measure the peak heap memory of every day, needs a build");
+    println!("                       with --features memory; it is measured, not limited");
+    println!("    --verbose          print the debug output of the solvers to stderr, twice for");
+    println!("                       everything, RUST_AOC_LOG=off|info|debug|trace does the same");
+    println!("    --port <n>         serve on port <n> of localhost, defaults to 8080; post the");
+    println!("                       input to /<year>/<day> to get the answers as json");
+    println!(
+        "    --origin <url>     let pages from <url> post to serve, like http://localhost:3000,"
+    );
+    println!("                       the pages of any other origin are refused");
+    println!("  Exit codes:");
+    println!("    0  every day was solved");
+    println!("    1  the arguments could not be parsed");
+    println!("    2  at least one day failed");
+    println!("    3  no day failed, but some days are not implemented");
 }
 
-fn create_filename(year: u32, day: u32, cmd: TestOrRun) -> PathBuf {
+fn create_filename(data_dir: &Path, year: u32, day: u32, cmd: TestOrRun) -> PathBuf {
     let t = match cmd {
-        TestOrRun::Test => "test",
-        TestOrRun::Run => "input",
-        _ => unreachable!(),
+        TestOrRun::Test | TestOrRun::Extract => "test",
+        TestOrRun::Run | TestOrRun::Verify => "input",
+        TestOrRun::Exec => unreachable!("exec is always given its input"),
+        TestOrRun::New => unreachable!("new does not read any input"),
+        TestOrRun::Serve => unreachable!("serve is posted its input"),
+        TestOrRun::Watch => unreachable!("watch reads both the example and the input"),
     };
 
     let y = "year".to_string() + &year.to_string();
     let f = "day".to_string() + &format!("{:02}.txt", day);
 
-    std::path::Path::new("data/").join(t).join(y).join(f)
-}
-
-fn dispatch(year: u32, day: u32, cmd: TestOrRun) -> Result<(String, String), String> {
-    let path = create_filename(year, day, cmd);
-    let filename = path.to_str().expect("path is valid unicode");
-
-    match year {
-        2024 => match day {
-            1 => year2024::day01::solve(filename),
-            2 => year2024::day02::solve(filename),
-            3 => year2024::day03::solve(filename),
-            4 => year2024::day04::solve(filename),
-            5 => year2024::day05::solve(filename),
-            6 => year2024::day06::solve(filename),
-            7 => year2024::day07::solve(filename),
-            8 => year2024::day08::solve(filename),
-            9 => year2024::day09::solve(filename),
-            10 => year2024::day10::solve(filename),
-            11 => year2024::day11::solve(filename),
-            12 => year2024::day12::solve(filename),
-            13 => year2024::day13::solve(filename),
-            14 => year2024::day14::solve(filename),
-            15 => year2024::day15::solve(filename),
-            16 => year2024::day16::solve(filename),
-            17 => year2024::day17::solve(filename),
-            18 => year2024::day18::solve(filename),
-            19 => year2024::day19::solve(filename),
-            20 => year2024::day20::solve(filename),
-            21 => year2024::day21::solve(filename),
-            22 => year2024::day22::solve(filename),
-            23 => year2024::day23::solve(filename),
-            24 => year2024::day24::solve(filename),
-            25 => year2024::day25::solve(filename),
-            _ => Err(format!("day not implemented {}", day)),
-        },
-        2025 => match day {
-            1 => year2025::day01::solve(filename),
-            _ => Err(format!("day not implemented {}", day)),
+    data_dir.join(t).join(y).join(f)
+}
+
+// the example to solve on is only given for the test command
+fn read_input(
+    config: &RunConfig,
+    year: u32,
+    day: u32,
+    example: Option<&Path>,
+) -> Result<String, String> {
+    if let Some(text) = &config.text {
+        return Ok(text.clone());
+    }
+
+    match config.filename.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .or(Err("could not read stdin".to_string()))?;
+
+            Ok(input)
+        }
+        Some(filename) => {
+            std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))
+        }
+        None => match example {
+            Some(path) => std::fs::read_to_string(path)
+                .or(Err(format!("could not read file {}", path.display()))),
+            None => config.inputs.get(year, day),
         },
-        _ => Err(format!("year not implemented {}", year)),
     }
 }
 
-fn print_solution(year: u32, day: u32, part1: &str, part2: &str) {
+fn dispatch(
+    config: &RunConfig,
+    year: u32,
+    day: u32,
+    example: Option<&Path>,
+) -> Result<(Answer, Answer, Timings), Failure> {
+    let solver = match rust_aoc::find(year, day) {
+        Some(solver) => solver,
+        None if rust_aoc::solvers().any(|s| s.year == year) => {
+            return Err(Failure::Unimplemented(format!(
+                "day not implemented {}",
+                day
+            )))
+        }
+        None => {
+            return Err(Failure::Unimplemented(format!(
+                "year not implemented {}",
+                year
+            )))
+        }
+    };
+
+    let (input, changes) =
+        input::normalize(&read_input(config, year, day, example).map_err(Failure::Error)?);
+
+    if config.warn_input && changes.any() {
+        eprintln!("warning: {} day {}: {}", year, day, changes);
+    }
+
+    // the examples bring the parameters of their sidecars, unless others were given
+    let params = match example {
+        Some(path) if config.params.is_empty() => examples::params(path).map_err(Failure::Error)?,
+        _ => config.params.clone(),
+    };
+
+    // a panicking solver fails its own day, the other days are still solved
+    let solved = runner::with_timeout(config.timeout, move || {
+        memory::measure(|| runner::isolate(|| solver.solve_timed(&input, &params)))
+    });
+
+    let Some((solved, peak)) = solved else {
+        return Err(Failure::TimedOut(
+            config.timeout.expect("only a timeout gives up"),
+        ));
+    };
+
+    let (part1, part2, mut timings) = solved
+        .map_err(|p| Failure::Error(p.to_string()))?
+        .map_err(|e| Failure::Error(e.to_string()))?;
+
+    if config.memory {
+        timings.peak_memory = Some(peak);
+    }
+
+    Ok((part1, part2, timings))
+}
+
+// multi-line answers are indented to line up below the part label
+fn format_answer(answer: &Answer) -> String {
+    answer.to_string().replace('\n', "\n          ")
+}
+
+// the examples of a day with more than one are told apart by their number
+fn example_number(example: Option<&Path>) -> Option<&str> {
+    let stem = example?.file_stem()?.to_str()?;
+
+    stem.split_once('-').map(|(_, number)| number)
+}
+
+fn print_solution(year: u32, day: u32, example: Option<&Path>, part1: &Answer, part2: &Answer) {
+    let example = example_number(example).map_or(String::new(), |n| format!(", example {}", n));
+
     println!(
-        "Advent of Code {}, Day {}:
+        "Advent of Code {}, Day {}{}:
   Part 1: {}
   Part 2: {}
 ",
-        year, day, part1, part2
+        year,
+        day,
+        example,
+        format_answer(part1),
+        format_answer(part2)
+    );
+}
+
+fn format_time(d: Duration) -> String {
+    format!("{:10.3} ms", d.as_secs_f64() * 1000.0)
+}
+
+fn format_memory(bytes: usize) -> String {
+    format!("{:9.1} MiB", bytes as f64 / (1 << 20) as f64)
+}
+
+// the memory column is only shown when the peak memory was measured
+fn print_timings(timings: &[(PuzzleDay, Timings)], budget: Duration) {
+    let memory_header = match timings.iter().any(|(_, t)| t.peak_memory.is_some()) {
+        true => format!(" | {:>13}", "Memory"),
+        false => String::new(),
+    };
+
+    println!(
+        "  Year Day | {:>13} | {:>13} | {:>13} | {:>13}{}",
+        "Parse", "Part 1", "Part 2", "Total", memory_header
+    );
+
+    let mut total = Duration::ZERO;
+
+    for ((year, day), t) in timings {
+        let marker = if t.total() > budget {
+            " <- over budget"
+        } else {
+            ""
+        };
+
+        let memory = t.peak_memory.map_or(String::new(), |bytes| {
+            format!(" | {}", format_memory(bytes))
+        });
+
+        println!(
+            "  {} {:>3} | {} | {} | {} | {}{}{}",
+            year,
+            day,
+            format_time(t.parse),
+            format_time(t.part_one),
+            format_time(t.part_two),
+            format_time(t.total()),
+            memory,
+            marker
+        );
+
+        total += t.total();
+    }
+
+    println!("  Total{:>54}{}", "", format_time(total));
+}
+
+const EXIT_FAILED: i32 = 2;
+const EXIT_UNIMPLEMENTED: i32 = 3;
+
+// the days of a batch run, sorted by how they ended
+#[derive(Debug, Default)]
+struct Summary {
+    solved: Vec<PuzzleDay>,
+    failed: Vec<PuzzleDay>,
+    timed_out: Vec<PuzzleDay>,
+    unimplemented: Vec<PuzzleDay>,
+}
+
+impl Summary {
+    // the examples of a day are reported one after the other, the day fails with any of them
+    fn record(&mut self, report: &Report) {
+        let day = (report.year, report.day);
+
+        let days = match report.result {
+            Ok(_) if self.failed.contains(&day) || self.timed_out.contains(&day) => return,
+            Ok(_) => &mut self.solved,
+            Err(Failure::Unimplemented(_)) => &mut self.unimplemented,
+            Err(Failure::Error(_)) => &mut self.failed,
+            Err(Failure::TimedOut(_)) => &mut self.timed_out,
+        };
+
+        if days.last() != Some(&day) {
+            days.push(day);
+        }
+
+        if report.result.is_err() {
+            self.solved.retain(|&solved| solved != day);
+        }
+    }
+
+    fn exit_code(&self) -> i32 {
+        if !self.failed.is_empty() || !self.timed_out.is_empty() {
+            EXIT_FAILED
+        } else if !self.unimplemented.is_empty() {
+            EXIT_UNIMPLEMENTED
+        } else {
+            0
+        }
+    }
+
+    fn lines(&self, elapsed: Duration) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Solved {} days in {:.3}s",
+            self.solved.len(),
+            elapsed.as_secs_f64()
+        )];
+
+        if !self.failed.is_empty() {
+            lines.push(format!(
+                "Failed {} days: {}",
+                self.failed.len(),
+                format_days(&self.failed)
+            ));
+        }
+
+        if !self.timed_out.is_empty() {
+            lines.push(format!(
+                "Timed out {} days: {}",
+                self.timed_out.len(),
+                format_days(&self.timed_out)
+            ));
+        }
+
+        if !self.unimplemented.is_empty() {
+            lines.push(format!(
+                "Not implemented {} days: {}",
+                self.unimplemented.len(),
+                format_days(&self.unimplemented)
+            ));
+        }
+
+        lines
+    }
+}
+
+// messages about the run itself go to stderr when stdout holds json or csv
+fn notice(config: &RunConfig, line: &str) {
+    if config.format == Format::Text {
+        println!("{}", line);
+    } else {
+        eprintln!("{}", line);
+    }
+}
+
+// creates the selected days from the template, run from the root of the repository
+fn scaffold(config: &RunConfig) -> i32 {
+    for &year in &config.years {
+        for &day in &config.days {
+            match scaffold::new_day(Path::new("."), year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.display());
+                    }
+                }
+                Err(s) => {
+                    println!("Error: {}", s);
+                    return EXIT_FAILED;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+// writes the examples of a saved puzzle page to the test data, next to their expected answers
+fn extract(config: &RunConfig) -> i32 {
+    let (year, day) = (config.years[0], config.days[0]);
+    let filename = config.filename.as_deref().expect("extract is given a page");
+
+    let examples = match std::fs::read_to_string(filename)
+        .or(Err(format!("could not read file {}", filename)))
+        .and_then(|page| rust_aoc::extract::extract(&page))
+    {
+        Ok(examples) => examples,
+        Err(s) => {
+            println!("Error: {}", s);
+            return EXIT_FAILED;
+        }
+    };
+
+    let path = create_filename(&config.data_dir, year, day, config.command);
+    let mut files = Vec::new();
+
+    for (i, example) in examples.iter().enumerate() {
+        // a day with more than one example numbers them, like day03-1.txt
+        let path = if examples.len() == 1 {
+            path.clone()
+        } else {
+            path.with_file_name(format!("day{:02}-{}.txt", day, i + 1))
+        };
+
+        files.push((
+            path.with_extension("expected"),
+            format_expected(&example.expected),
+        ));
+        files.push((path, example.input.clone()));
+    }
+
+    // numbered examples take the place of the single one, which the harness would run as well
+    let replaced = match examples.len() {
+        1 => Vec::new(),
+        _ => vec![path.clone(), path.with_extension("expected")],
+    };
+
+    // the files of the new command are empty and may be replaced, anything else was typed in
+    for path in files.iter().map(|(path, _)| path).chain(&replaced) {
+        if std::fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            println!("Error: {} already exists", path.display());
+            return EXIT_FAILED;
+        }
+    }
+
+    for path in replaced.iter().filter(|path| path.exists()) {
+        if std::fs::remove_file(path).is_err() {
+            println!("Error: could not remove file {}", path.display());
+            return EXIT_FAILED;
+        }
+
+        println!("Removed {}", path.display());
+    }
+
+    for (path, contents) in files {
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, contents));
+
+        if written.is_err() {
+            println!("Error: could not write file {}", path.display());
+            return EXIT_FAILED;
+        }
+
+        println!("Wrote {}", path.display());
+    }
+
+    0
+}
+
+// checks the answers for the real inputs against the answers files of the years
+fn verify(config: &RunConfig) -> i32 {
+    let mut stores = BTreeMap::new();
+
+    for &year in &config.years {
+        let path = config
+            .data_dir
+            .join("answers")
+            .join(format!("year{}.toml", year));
+
+        match AnswerStore::load(&path) {
+            Ok(store) => stores.insert(year, store),
+            Err(s) => {
+                println!("Error: {}", s);
+                return 1;
+            }
+        };
+    }
+
+    let (days, unimplemented) = config.selection();
+
+    if !unimplemented.is_empty() {
+        println!("Not implemented: {}\n", format_days(&unimplemented));
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    runner::run_ordered(
+        &days,
+        config.jobs,
+        |&(year, day)| dispatch(config, year, day, None),
+        |&(year, day), result| match result {
+            Ok((part1, part2, _)) => {
+                let store = &stores[&year];
+                let checks = [store.check(day, 0, &part1), store.check(day, 1, &part2)];
+
+                println!(
+                    "{} day {:>2}: part 1 {}, part 2 {}",
+                    year, day, checks[0], checks[1]
+                );
+
+                for check in checks {
+                    match check {
+                        Check::Pass => passed += 1,
+                        Check::Fail { .. } => failed += 1,
+                        Check::Missing => missing += 1,
+                    }
+                }
+            }
+            Err(s) => {
+                println!("{} day {:>2}: Error: {}", year, day, s);
+                failed += 2;
+            }
+        },
+    );
+
+    println!(
+        "\nVerified {} days: {} passed, {} failed, {} missing",
+        days.len(),
+        passed,
+        failed,
+        missing
     );
+
+    if failed > 0 {
+        EXIT_FAILED
+    } else {
+        0
+    }
+}
+
+// how long serve solves a day without --timeout
+const SERVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// answers the inputs posted to /{year}/{day} until the process is stopped
+fn serve(config: &RunConfig) -> i32 {
+    let listener = match TcpListener::bind(("127.0.0.1", config.port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            println!("Error: could not listen on port {}: {}", config.port, e);
+            return EXIT_FAILED;
+        }
+    };
+
+    // a slow day must not hold on to its connection forever
+    let settings = serve::Settings {
+        origin: config.origin.clone(),
+        timeout: config.timeout.unwrap_or(SERVE_TIMEOUT),
+    };
+
+    println!("Listening on http://127.0.0.1:{}", config.port);
+    serve::serve(listener, settings);
+
+    0
+}
+
+fn solve_watched(
+    year: u32,
+    day: u32,
+    input: Result<String, String>,
+    params: &Overrides,
+    timeout: Option<Duration>,
+) -> watch::Outcome {
+    let solver = rust_aoc::find(year, day)
+        .ok_or_else(|| format!("{} day {} is not implemented", year, day))?;
+
+    let input = input::normalize(&input?).0;
+    let params = params.clone();
+
+    let solved = runner::with_timeout(timeout, move || {
+        runner::isolate(|| solver.solve_with(&input, &params))
+    });
+
+    let Some(solved) = solved else {
+        return Err(Failure::TimedOut(timeout.expect("only a timeout gives up")).to_string());
+    };
+
+    let (part1, part2) = solved
+        .map_err(|p| p.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    Ok([part1.to_string(), part2.to_string()])
+}
+
+// re-runs the day on its examples and its input whenever they or the source change
+fn watch(config: &RunConfig) -> i32 {
+    let (year, day) = (config.years[0], config.days[0]);
+    let dir = config.data_dir.join("test");
+
+    let mut examples = examples::find(&dir, year, day);
+
+    // the example may still be written while watching
+    if examples.is_empty() {
+        examples.push(examples::path(&dir, year, day));
+    }
+
+    let mut targets: Vec<watch::Target> = examples
+        .into_iter()
+        .map(|example| watch::Target {
+            name: match example_number(Some(&example)) {
+                Some(n) => format!("example {}", n),
+                None => "example".to_string(),
+            },
+            path: example.clone(),
+            solve: Box::new(move || {
+                let input = std::fs::read_to_string(&example)
+                    .or(Err(format!("could not read file {}", example.display())));
+
+                // the example may need the parameters of its sidecar, unless others were given
+                let params = match config.params.is_empty() {
+                    true => examples::params(&example)?,
+                    false => config.params.clone(),
+                };
+
+                solve_watched(year, day, input, &params, config.timeout)
+            }),
+        })
+        .collect();
+
+    targets.push(watch::Target {
+        name: "input".to_string(),
+        path: config.inputs.path(year, day),
+        solve: Box::new(|| {
+            let input = config.inputs.get(year, day);
+
+            solve_watched(year, day, input, &config.params, config.timeout)
+        }),
+    });
+
+    watch::watch(&targets, &watch::sources())
 }
 
 fn main() {
@@ -147,29 +1062,492 @@ fn main() {
         }
     };
 
-    if config.days.is_empty() {
-        for d in 1..=25 {
-            let (part1, part2) = match dispatch(config.year, d, config.command) {
-                Ok(x) => x,
-                Err(s) => {
-                    println!("Error: {}", s);
-                    std::process::exit(1);
-                }
-            };
+    if let Some(level) = config.log_level {
+        trace::set_level(level);
+    }
 
-            print_solution(config.year, d, &part1, &part2);
+    if config.command == TestOrRun::Extract {
+        std::process::exit(extract(&config));
+    }
+
+    if config.command == TestOrRun::New {
+        std::process::exit(scaffold(&config));
+    }
+
+    if config.command == TestOrRun::Verify {
+        std::process::exit(verify(&config));
+    }
+
+    if config.command == TestOrRun::Watch {
+        std::process::exit(watch(&config));
+    }
+
+    if config.command == TestOrRun::Serve {
+        std::process::exit(serve(&config));
+    }
+
+    let (days, unimplemented) = config.selection();
+
+    let start = Instant::now();
+    let mut timings = Vec::new();
+    let mut summary = Summary::default();
+
+    // report what cannot be solved before spending any time on the rest
+    if !unimplemented.is_empty() {
+        notice(
+            &config,
+            &format!("Not implemented: {}\n", format_days(&unimplemented)),
+        );
+    }
+
+    // the missing days still get a record of their own in json and csv, with --fail-fast
+    // they are the only days run
+    let selected = match config.fail_fast && !unimplemented.is_empty() {
+        true => unimplemented,
+        false => config.selected(),
+    };
+
+    if config.time && config.jobs > 1 && days.len() > 1 {
+        eprintln!(
+            "warning: timing {} days on {} jobs, the days slow each other down",
+            days.len(),
+            config.jobs
+        );
+    }
+
+    if config.format == Format::Csv {
+        println!("{}", report::CSV_HEADER);
+    }
+
+    runner::run_ordered(
+        &config.runs(&selected),
+        config.jobs,
+        |((year, day), example)| dispatch(&config, *year, *day, example.as_deref()),
+        |&((year, day), ref example), result| {
+            let report = Report { year, day, result };
+
+            match config.format {
+                Format::Json => println!("{}", report.to_json()),
+                Format::Csv => println!("{}", report.to_csv()),
+                Format::Text => match &report.result {
+                    Ok((part1, part2, t)) => {
+                        print_solution(year, day, example.as_deref(), part1, part2);
+                        timings.push(((year, day), *t));
+                    }
+                    // already reported before the run
+                    Err(Failure::Unimplemented(_)) => {}
+                    Err(s) => println!("Error: {}", s),
+                },
+            }
+
+            summary.record(&report);
+
+            if config.fail_fast
+                && matches!(report.result, Err(Failure::Error(_) | Failure::TimedOut(_)))
+            {
+                std::process::exit(summary.exit_code());
+            }
+        },
+    );
+
+    if config.format == Format::Text && config.time {
+        print_timings(&timings, config.budget);
+
+        // a day that timed out is still running and competes with the days after it
+        if !summary.timed_out.is_empty() {
+            println!("  Days solved after a timeout may show longer times");
         }
-    } else {
-        for d in &config.days {
-            let (part1, part2) = match dispatch(config.year, *d, config.command) {
-                Ok(x) => x,
-                Err(s) => {
-                    println!("Error: {}", s);
-                    std::process::exit(1);
-                }
-            };
 
-            print_solution(config.year, *d, &part1, &part2);
+        println!();
+    }
+
+    for line in summary.lines(start.elapsed()) {
+        notice(&config, &line);
+    }
+
+    std::process::exit(summary.exit_code());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<RunConfig, &'static str> {
+        RunConfig::parse(args.split(' ').map(String::from))
+    }
+
+    #[test]
+    fn test_parse_input_options() {
+        let config = parse("rust_aoc run 2024 3 --input - --data-dir shared").unwrap();
+
+        assert_eq!(vec![3], config.days);
+        assert!(!config.warn_input);
+        assert!(
+            parse("rust_aoc run 2024 3 --warn-input")
+                .unwrap()
+                .warn_input
+        );
+        assert_eq!(Some("-"), config.filename.as_deref());
+        assert_eq!(
+            Path::new("shared/input/year2024/day03.txt"),
+            create_filename(&config.data_dir, 2024, 3, config.command)
+        );
+    }
+
+    // the test command solves a day once for every example, the run command once on its input
+    #[test]
+    fn test_runs() {
+        let config = parse("rust_aoc test 2024 17").unwrap();
+
+        assert_eq!(
+            vec![
+                (
+                    (2024, 17),
+                    Some(PathBuf::from("data/test/year2024/day17-1.txt"))
+                ),
+                (
+                    (2024, 17),
+                    Some(PathBuf::from("data/test/year2024/day17-2.txt"))
+                )
+            ],
+            config.runs(&[(2024, 17)])
+        );
+        assert_eq!(
+            Some("2"),
+            example_number(config.runs(&[(2024, 17)])[1].1.as_deref())
+        );
+        assert_eq!(
+            vec![((2024, 17), None)],
+            parse("rust_aoc run 2024 17").unwrap().runs(&[(2024, 17)])
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let config = parse("rust_aoc verify 2024").unwrap();
+
+        assert_eq!(TestOrRun::Verify, config.command);
+        assert!(config.days.is_empty());
+        assert_eq!(
+            Path::new("data/input/year2024/day01.txt"),
+            create_filename(&config.data_dir, 2024, 1, config.command)
+        );
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let config = parse("rust_aoc test 2024 14 --param width=11 --param height=7").unwrap();
+
+        let mut params = Overrides::default();
+        params.set("width", "11");
+        params.set("height", "7");
+
+        assert_eq!(params, config.params);
+        assert!(parse("rust_aoc test 2024 14 --param width").is_err());
+        assert!(parse("rust_aoc test 2024 --param width=11").is_err());
+
+        // the day fails instead of dividing by a room of width 0
+        let config = parse("rust_aoc test 2024 14 --param width=0").unwrap();
+        let example = Path::new("data/test/year2024/day14.txt");
+
+        assert_eq!(
+            Err(Failure::Error(
+                "value 0 is out of range for parameter width".to_string()
+            )),
+            dispatch(&config, 2024, 14, Some(example))
+        );
+    }
+
+    #[test]
+    fn test_parse_exec() {
+        let config = parse("rust_aoc exec 2024 14 --input robots.txt").unwrap();
+
+        assert_eq!(TestOrRun::Exec, config.command);
+        assert_eq!(Some("robots.txt"), config.filename.as_deref());
+        assert!(config.time);
+
+        // the text is used as it is, like the input of day 3 with its backslashes
+        for text in ["3___4\n4___3", "mul(2,4)\\n\\\\"] {
+            let config = parse(&format!("rust_aoc exec 2024 1 --string {}", text)).unwrap();
+
+            assert_eq!(Ok(text.to_string()), read_input(&config, 2024, 1, None));
+        }
+
+        assert!(parse("rust_aoc exec 2024 1").is_err());
+        assert!(parse("rust_aoc exec 2024 --string 1").is_err());
+        assert!(parse("rust_aoc exec 2024 1 --string 1 --input day.txt").is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        let report = |day, result| Report {
+            year: 2025,
+            day,
+            result,
+        };
+
+        let mut summary = Summary::default();
+
+        summary.record(&report(1, Ok((1.into(), 2.into(), Timings::default()))));
+        assert_eq!(0, summary.exit_code());
+
+        summary.record(&report(2, Err(Failure::Unimplemented(String::new()))));
+        summary.record(&report(3, Err(Failure::Unimplemented(String::new()))));
+        assert_eq!(EXIT_UNIMPLEMENTED, summary.exit_code());
+
+        summary.record(&report(4, Err(Failure::Error(String::new()))));
+        assert_eq!(EXIT_FAILED, summary.exit_code());
+
+        summary.record(&report(5, Err(Failure::TimedOut(Duration::from_secs(1)))));
+
+        // a day fails with any of its examples
+        for result in [
+            Ok(Timings::default()),
+            Err(String::new()),
+            Ok(Timings::default()),
+        ] {
+            let result = result
+                .map(|t| (1.into(), 2.into(), t))
+                .map_err(Failure::Error);
+
+            summary.record(&report(6, result));
+        }
+
+        assert_eq!(
+            vec![
+                "Solved 1 days in 0.000s",
+                "Failed 2 days: 2025: 4, 6",
+                "Timed out 1 days: 2025: 5",
+                "Not implemented 2 days: 2025: 2-3"
+            ],
+            summary.lines(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        let config = parse("rust_aoc new 2025 2-3").unwrap();
+
+        assert_eq!(TestOrRun::New, config.command);
+        assert_eq!(vec![2, 3], config.days);
+        assert!(parse("rust_aoc new 2025").is_err());
+    }
+
+    #[test]
+    fn test_fetcher() {
+        let session = || Some("53cr37".to_string());
+        let contact = || Some("me@example.com".to_string());
+
+        // a session token alone does not fetch anything
+        assert!(fetcher(None, session(), contact()).unwrap().is_none());
+        assert!(fetcher(Some("aoc".to_string()), None, contact()).is_err());
+        assert!(fetcher(Some("http://localhost:8080".to_string()), session(), None).is_err());
+
+        assert_eq!(
+            "HttpFetcher { base: \"https://adventofcode.com\", contact: \"me@example.com\", .. }",
+            format!(
+                "{:?}",
+                fetcher(Some("aoc".to_string()), session(), contact())
+                    .unwrap()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            "DirFetcher { dir: \"mirror\" }",
+            format!(
+                "{:?}",
+                fetcher(Some("mirror".to_string()), None, None)
+                    .unwrap()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_extract() {
+        let config = parse("rust_aoc extract 2024 3 --input day03.html").unwrap();
+
+        assert_eq!(
+            Path::new("data/test/year2024/day03.txt"),
+            create_filename(&config.data_dir, 2024, 3, config.command)
+        );
+        assert!(parse("rust_aoc extract 2024 3").is_err());
+        assert!(parse("rust_aoc extract 2024 3 --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        let config = parse("rust_aoc serve --port 3000").unwrap();
+
+        assert_eq!(TestOrRun::Serve, config.command);
+        assert_eq!(3000, config.port);
+        assert_eq!(8080, parse("rust_aoc serve").unwrap().port);
+        assert_eq!(None, config.origin);
+        assert_eq!(
+            Some("http://localhost:3000"),
+            parse("rust_aoc serve --origin http://localhost:3000")
+                .unwrap()
+                .origin
+                .as_deref()
+        );
+        assert!(parse("rust_aoc serve --port http").is_err());
+        assert!(parse("rust_aoc run").is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        let config = parse("rust_aoc watch 2024 16").unwrap();
+
+        assert_eq!(TestOrRun::Watch, config.command);
+        assert!(parse("rust_aoc watch 2024").is_err());
+        assert!(parse("rust_aoc watch 2024 16-17").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let config = parse("rust_aoc run 2024 --timeout 250ms").unwrap();
+
+        assert_eq!(Some(Duration::from_millis(250)), config.timeout);
+        assert_eq!(None, parse("rust_aoc run 2024").unwrap().timeout);
+        assert!(parse("rust_aoc run 2024 --timeout soon").is_err());
+
+        // only a build that counts the allocations can measure them
+        match parse("rust_aoc run 2024 --memory") {
+            Ok(config) => assert!(cfg!(feature = "memory") && config.memory && config.time),
+            Err(e) => assert_eq!("--memory needs a build with --features memory", e),
         }
     }
+
+    #[test]
+    fn test_parse_verbose() {
+        assert_eq!(None, parse("rust_aoc run 2024").unwrap().log_level);
+        assert_eq!(
+            Some(Level::Debug),
+            parse("rust_aoc run 2024 --verbose").unwrap().log_level
+        );
+        assert_eq!(
+            Some(Level::Trace),
+            parse("rust_aoc run 2024 16 --verbose --verbose")
+                .unwrap()
+                .log_level
+        );
+    }
+
+    #[test]
+    fn test_parse_days() {
+        let config = parse("rust_aoc run 2024 1-10,15 20.. 3").unwrap();
+
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 15, 20, 21, 22, 23, 24, 25],
+            config.days
+        );
+
+        assert_eq!(
+            vec![1, 2, 3, 5],
+            parse("rust_aoc run 2024 ..3,5..=5").unwrap().days
+        );
+
+        assert!(parse("rust_aoc run 2024 0").is_err());
+        assert!(parse("rust_aoc run 2024 26").is_err());
+        assert!(parse("rust_aoc run 2024 0-3").is_err());
+        assert!(parse("rust_aoc run 2024 5-3").is_err());
+        assert!(parse("rust_aoc run 2024 1,,2").is_err());
+    }
+
+    #[test]
+    fn test_parse_years() {
+        assert_eq!(
+            vec![2024, 2025],
+            parse("rust_aoc run 2025,2024 1").unwrap().years
+        );
+
+        let years: BTreeSet<u32> = rust_aoc::solvers().map(|s| s.year).collect();
+
+        assert_eq!(
+            years.into_iter().collect::<Vec<_>>(),
+            parse("rust_aoc run all").unwrap().years
+        );
+        assert!(parse("rust_aoc run 2024,").is_err());
+        assert!(parse("rust_aoc run 2024,2025 1 --input day.txt").is_err());
+    }
+
+    #[test]
+    fn test_selection() {
+        let registered = |year| -> Vec<PuzzleDay> {
+            rust_aoc::solvers()
+                .filter(|s| s.year == year)
+                .map(|s| (s.year, s.day))
+                .collect()
+        };
+
+        let (days, unimplemented) = parse("rust_aoc run 2024,2025 1 2").unwrap().selection();
+        let selected = [(2024, 1), (2024, 2), (2025, 1), (2025, 2)];
+
+        // the missing days keep their place among the others
+        assert_eq!(
+            selected.to_vec(),
+            parse("rust_aoc run 2024,2025 1 2").unwrap().selected()
+        );
+
+        for day in selected {
+            let implemented = rust_aoc::find(day.0, day.1).is_some();
+
+            assert_eq!(implemented, days.contains(&day));
+            assert_eq!(!implemented, unimplemented.contains(&day));
+        }
+
+        assert_eq!(selected.len(), days.len() + unimplemented.len());
+
+        // no days select every day of the years, implemented or not
+        let (days, unimplemented) = parse("rust_aoc run 2025,2023").unwrap().selection();
+
+        assert!(registered(2023).is_empty());
+        assert_eq!(registered(2025), days);
+        assert_eq!(50 - days.len(), unimplemented.len());
+        assert!(format_days(&unimplemented).starts_with("2023: 1-25; 2025: "));
+    }
+
+    #[test]
+    fn test_format_days() {
+        assert_eq!(
+            "2024: 1-3, 7; 2025: 2",
+            format_days(&[(2024, 1), (2024, 2), (2024, 3), (2024, 7), (2025, 2)])
+        );
+    }
+
+    #[test]
+    fn test_input_requires_single_day() {
+        assert!(parse("rust_aoc run 2024 --input day.txt").is_err());
+        assert!(parse("rust_aoc run 2024 1 2 --input day.txt").is_err());
+        assert!(parse("rust_aoc run 2024 1 --input").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(Some(Duration::from_secs(1)), parse_duration("1s"));
+        assert_eq!(Some(Duration::from_millis(250)), parse_duration("250ms"));
+        assert_eq!(Some(Duration::from_millis(500)), parse_duration("0.5"));
+        assert_eq!(None, parse_duration("soon"));
+        assert_eq!(None, parse_duration("-1s"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(Format::Text, parse("rust_aoc run 2024").unwrap().format);
+        assert_eq!(
+            Format::Json,
+            parse("rust_aoc run 2024 --format json").unwrap().format
+        );
+        assert!(parse("rust_aoc run 2024 --format yaml").is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(3, parse("rust_aoc run 2024 --jobs 3").unwrap().jobs);
+        assert!(parse("rust_aoc run 2024 --jobs 0").is_err());
+        assert!(parse("rust_aoc run 2024 --jobs many").is_err());
+
+        assert_eq!(1, parse("rust_aoc run 2024 --time").unwrap().jobs);
+        assert_eq!(2, parse("rust_aoc run 2024 --time --jobs 2").unwrap().jobs);
+    }
 }
@@ -0,0 +1,97 @@
+// peak heap memory of a task, counted per thread by a wrapper around the system allocator
+//
+// memory freed by another thread than the one that allocated it is counted against the freeing
+// thread, which is fine for solvers that keep their data on the thread that runs them
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+pub struct Counting;
+
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn record(delta: isize) {
+    // the thread locals are gone while a thread shuts down, its last frees are not counted
+    let _ = CURRENT.try_with(|current| {
+        let now = current.get() + delta;
+        current.set(now);
+
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+
+        if !new.is_null() {
+            record(new_size as isize - layout.size() as isize);
+        }
+
+        new
+    }
+}
+
+// runs `task` and returns the most heap memory it held at once, in bytes
+// only allocations through `Counting` are seen, so this is 0 unless it is the global allocator
+pub fn measure<R>(task: impl FnOnce() -> R) -> (R, usize) {
+    let start = CURRENT.get();
+    PEAK.set(start);
+
+    let res = task();
+
+    (res, (PEAK.get() - start).max(0) as usize)
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (len, peak) = measure(|| {
+            let grid = std::hint::black_box(vec![0u8; 1 << 20]);
+            grid.len()
+        });
+
+        assert_eq!(1 << 20, len);
+        assert!(peak >= 1 << 20);
+
+        let (_, peak) = measure(|| 2 + 2);
+        assert_eq!(0, peak);
+    }
+}
@@ -0,0 +1,232 @@
+// machine readable records of the result of solving a single day
+
+use std::{
+    fmt::{self, Write},
+    time::Duration,
+};
+
+use crate::{Answer, Timings};
+
+// why a day could not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    // there is no solver for the day or the year
+    Unimplemented(String),
+    Error(String),
+    // the day took longer than the timeout
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Unimplemented(s) | Failure::Error(s) => write!(f, "{}", s),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {:.3}s", timeout.as_secs_f64())
+            }
+        }
+    }
+}
+
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub result: Result<(Answer, Answer, Timings), Failure>,
+}
+
+pub const CSV_HEADER: &str = "year,day,status,part_one,part_two,parse_ms,part_one_ms,part_two_ms,total_ms,peak_memory_bytes,error";
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+
+    res.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+pub fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) => n.to_string(),
+        Answer::Signed(n) => n.to_string(),
+        Answer::String(s) => json_string(s),
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+pub fn json_timings(t: &Timings) -> String {
+    format!(
+        "{{\"parse\":{:.6},\"part_one\":{:.6},\"part_two\":{:.6},\"total\":{:.6}}}",
+        millis(t.parse),
+        millis(t.part_one),
+        millis(t.part_two),
+        millis(t.total())
+    )
+}
+
+fn json_memory(t: &Timings) -> String {
+    t.peak_memory
+        .map_or("null".to_string(), |bytes| bytes.to_string())
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Report {
+    pub fn status(&self) -> &'static str {
+        match self.result {
+            Ok(_) => "ok",
+            Err(Failure::Unimplemented(_)) => "unimplemented",
+            Err(Failure::Error(_)) => "error",
+            Err(Failure::TimedOut(_)) => "timeout",
+        }
+    }
+
+    // a single line json object
+    pub fn to_json(&self) -> String {
+        let (part1, part2, time_ms, memory, error) = match &self.result {
+            Ok((part1, part2, t)) => (
+                json_answer(part1),
+                json_answer(part2),
+                json_timings(t),
+                json_memory(t),
+                "null".to_string(),
+            ),
+            Err(e) => (
+                "null".to_string(),
+                "null".to_string(),
+                "null".to_string(),
+                "null".to_string(),
+                json_string(&e.to_string()),
+            ),
+        };
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"status\":\"{}\",\"part_one\":{},\"part_two\":{},\"time_ms\":{},\"peak_memory_bytes\":{},\"error\":{}}}",
+            self.year,
+            self.day,
+            self.status(),
+            part1,
+            part2,
+            time_ms,
+            memory,
+            error
+        )
+    }
+
+    // a csv row matching `CSV_HEADER`
+    pub fn to_csv(&self) -> String {
+        let fields = match &self.result {
+            Ok((part1, part2, t)) => [
+                csv_field(&part1.to_string()),
+                csv_field(&part2.to_string()),
+                format!("{:.6}", millis(t.parse)),
+                format!("{:.6}", millis(t.part_one)),
+                format!("{:.6}", millis(t.part_two)),
+                format!("{:.6}", millis(t.total())),
+                t.peak_memory
+                    .map_or(String::new(), |bytes| bytes.to_string()),
+                String::new(),
+            ],
+            Err(e) => [
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                csv_field(&e.to_string()),
+            ],
+        };
+
+        format!(
+            "{},{},{},{}",
+            self.year,
+            self.day,
+            self.status(),
+            fields.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved() -> Report {
+        Report {
+            year: 2024,
+            day: 17,
+            result: Ok((
+                Answer::from("4,6,3"),
+                Answer::Integer(117440),
+                Timings {
+                    parse: Duration::from_micros(1500),
+                    ..Timings::default()
+                },
+            )),
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            "{\"year\":2024,\"day\":17,\"status\":\"ok\",\"part_one\":\"4,6,3\",\"part_two\":117440,\"time_ms\":{\"parse\":1.500000,\"part_one\":0.000000,\"part_two\":0.000000,\"total\":1.500000},\"peak_memory_bytes\":null,\"error\":null}",
+            solved().to_json()
+        );
+
+        let failed = Report {
+            year: 2025,
+            day: 2,
+            result: Err(Failure::Unimplemented(
+                "day not implemented \"2\"".to_string(),
+            )),
+        };
+
+        assert_eq!(
+            "{\"year\":2025,\"day\":2,\"status\":\"unimplemented\",\"part_one\":null,\"part_two\":null,\"time_ms\":null,\"peak_memory_bytes\":null,\"error\":\"day not implemented \\\"2\\\"\"}",
+            failed.to_json()
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            "2024,17,ok,\"4,6,3\",117440,1.500000,0.000000,0.000000,1.500000,,",
+            solved().to_csv()
+        );
+
+        let failed = Report {
+            year: 2024,
+            day: 3,
+            result: Err(Failure::Error("could not read file day03.txt".to_string())),
+        };
+
+        assert_eq!(
+            "2024,3,error,,,,,,,,could not read file day03.txt",
+            failed.to_csv()
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Once,
+    },
+    thread::{self, available_parallelism},
+    time::Duration,
+};
+
+// the number of workers to use when none is configured
+pub fn default_jobs() -> usize {
+    available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+// runs `task` for every item on a pool of `jobs` worker threads
+// the results are handed to `report` in the order of the items, as soon as all previous items are done
+pub fn run_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    task: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(&T, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, task) = (&next, &task);
+
+            s.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+
+                if i >= items.len() || sender.send((i, task(&items[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        // only the workers hold senders now, so the receiver finishes once they are all done
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut current = 0;
+
+        for (i, result) in receiver {
+            pending.insert(i, result);
+
+            while let Some(result) = pending.remove(&current) {
+                report(&items[current], result);
+                current += 1;
+            }
+        }
+    });
+}
+
+// a panic caught by `isolate`, with the place it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    // set while `isolate` runs a task, whose panics are recorded instead of printed
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(s) => s.to_string(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_else(|| "Box<dyn Any>".to_string()),
+    }
+}
+
+// the location of a panic is only known to the panic hook, panics outside of `isolate` still go
+// to the previous hook
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATED.get() {
+                return previous(info);
+            }
+
+            CAUGHT.set(Some(Panic {
+                message: payload_message(info.payload()),
+                location: info.location().map(|l| l.to_string()),
+            }));
+        }));
+    });
+}
+
+// runs `task` and returns its panic as an error, so that one failing day does not end the run
+// the task is not resumed after a panic, so whatever it borrowed is not observed in a broken state
+pub fn isolate<R>(task: impl FnOnce() -> R) -> Result<R, Panic> {
+    install_hook();
+
+    let outer = ISOLATED.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(task));
+    ISOLATED.set(outer);
+
+    result.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: payload_message(payload.as_ref()),
+            location: None,
+        })
+    })
+}
+
+// runs `task` on its own thread and gives up waiting for it after `timeout`, returning None
+// a thread cannot be stopped, so a task that never finishes keeps running until the process exits
+pub fn with_timeout<R>(
+    timeout: Option<Duration>,
+    task: impl FnOnce() -> R + Send + 'static,
+) -> Option<R>
+where
+    R: Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(task());
+    };
+
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || sender.send(task()));
+
+    receiver.recv_timeout(timeout).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_results_are_reported_in_order() {
+        let items: Vec<u64> = (0..16).collect();
+        let mut reported = Vec::new();
+
+        // later items finish first
+        run_ordered(
+            &items,
+            4,
+            |i| {
+                thread::sleep(Duration::from_millis(16 - i));
+                i * 2
+            },
+            |i, result| reported.push((*i, result)),
+        );
+
+        assert_eq!(
+            items.iter().map(|i| (*i, i * 2)).collect::<Vec<_>>(),
+            reported
+        );
+    }
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(Ok(4), isolate(|| 2 + 2));
+
+        let caught = isolate(|| -> u32 { panic!("character not on keypad") }).unwrap_err();
+
+        assert_eq!("character not on keypad", caught.message);
+        assert!(caught.to_string().starts_with("panicked at src/runner.rs:"));
+
+        let caught = isolate(|| "3 4".parse::<u32>().unwrap()).unwrap_err();
+        assert!(caught.message.contains("InvalidDigit"));
+    }
+
+    #[test]
+    fn test_panics_do_not_stop_other_items() {
+        let items: Vec<u32> = (0..8).collect();
+        let mut reported = Vec::new();
+
+        run_ordered(
+            &items,
+            4,
+            |&i| isolate(|| if i == 3 { panic!("day {}", i) } else { i }),
+            |_, result| reported.push(result.map_err(|p| p.message)),
+        );
+
+        assert_eq!(Err("day 3".to_string()), reported[3]);
+        assert_eq!(7, reported.iter().filter(|r| r.is_ok()).count());
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(Some(4), with_timeout(None, || 2 + 2));
+        assert_eq!(
+            Some(4),
+            with_timeout(Some(Duration::from_secs(10)), || 2 + 2)
+        );
+
+        let forever = with_timeout(Some(Duration::from_millis(10)), || loop {
+            thread::sleep(Duration::from_millis(100));
+        });
+        assert_eq!(None::<()>, forever);
+    }
+
+    #[test]
+    fn test_no_items() {
+        let mut reported = 0;
+
+        run_ordered(&[] as &[u32], 4, |i| *i, |_, _| reported += 1);
+
+        assert_eq!(0, reported);
+    }
+}
@@ -0,0 +1,187 @@
+// generates the files of a new day from src/template/template.rs
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+static TEMPLATE: &str = include_str!("template/template.rs");
+
+fn module(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).or(Err(format!("could not read file {}", path.display())))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).or(Err(format!("could not create {}", dir.display())))?;
+    }
+
+    fs::write(path, contents).or(Err(format!("could not write file {}", path.display())))
+}
+
+pub fn render(year: u32, day: u32) -> String {
+    TEMPLATE
+        .replace("20XX", &year.to_string())
+        .replace("XX", &day.to_string())
+}
+
+// adds `pub mod {module};` to `file` among the modules of the same kind, like year2024 or day01,
+// keeping them sorted and everything else in the file as it was
+fn declare(file: &str, module: &str) -> String {
+    let kind = format!(
+        "pub mod {}",
+        module.trim_end_matches(|c: char| c.is_ascii_digit())
+    );
+    let declaration = format!("pub mod {};", module);
+
+    let mut lines: Vec<&str> = file.lines().collect();
+
+    let position = if let Some(i) = lines
+        .iter()
+        .rposition(|l| l.starts_with(&kind) && *l < declaration.as_str())
+    {
+        i + 1
+    } else if let Some(i) = lines.iter().position(|l| l.starts_with(&kind)) {
+        i
+    } else {
+        lines
+            .iter()
+            .rposition(|l| l.starts_with("pub mod "))
+            .map_or(0, |i| i + 1)
+    };
+
+    lines.insert(position, &declaration);
+
+    lines.join("\n") + "\n"
+}
+
+// creates the solver of a new day and its empty data files below `root`
+// returns every file that was written
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "day {} is outside of the allowed range [1, 25]",
+            day
+        ));
+    }
+
+    let src = root.join("src").join(format!("year{}", year));
+    let solver = src.join(format!("{}.rs", module(day)));
+
+    if solver.exists() {
+        return Err(format!("{} already exists", solver.display()));
+    }
+
+    let mut written = Vec::new();
+
+    // build.rs registers the day once its module is declared
+    let mod_rs = src.join("mod.rs");
+
+    let year_module = if mod_rs.exists() {
+        read(&mod_rs)?
+    } else {
+        let lib_rs = root.join("src").join("lib.rs");
+
+        write(&lib_rs, &declare(&read(&lib_rs)?, &format!("year{}", year)))?;
+        written.push(lib_rs);
+
+        String::new()
+    };
+
+    write(&solver, &render(year, day))?;
+    written.push(solver);
+
+    write(&mod_rs, &declare(&year_module, &module(day)))?;
+    written.push(mod_rs);
+
+    // never overwrite data that is already there
+    // the empty sidecar makes tests/examples.rs run the day without checking any answers
+    for (kind, extension) in [("test", "txt"), ("test", "expected"), ("input", "txt")] {
+        let path = root
+            .join("data")
+            .join(kind)
+            .join(format!("year{}", year))
+            .join(format!("{}.{}", module(day), extension));
+
+        if !path.exists() {
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let solver = render(2025, 2);
+
+        assert!(!solver.contains("XX"));
+        assert!(solver.contains("const YEAR: u32 = 2025;"));
+        assert!(solver.contains("const DAY: u32 = 2;"));
+    }
+
+    #[test]
+    fn test_declare() {
+        let year = "pub mod day01;\npub mod day03;\nmod grid;\n\n// shared by the days\n";
+
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\npub mod day03;\nmod grid;\n\n// shared by the days\n",
+            declare(year, "day02")
+        );
+        assert_eq!(
+            "pub mod day00;\npub mod day01;\npub mod day03;\nmod grid;\n\n// shared by the days\n",
+            declare(year, "day00")
+        );
+        assert_eq!("pub mod day05;\n", declare("", "day05"));
+        assert_eq!(
+            "pub mod answer;\npub mod year2024;\n",
+            declare("pub mod answer;\n", "year2024")
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("rust_aoc_scaffold_{}", std::process::id()));
+
+        write(
+            &root.join("src/lib.rs"),
+            "pub mod solution;\npub mod year2024;\n\npub use solution::Solver;\n",
+        )
+        .unwrap();
+
+        new_day(&root, 2025, 2).unwrap();
+        new_day(&root, 2025, 1).unwrap();
+
+        assert!(new_day(&root, 2025, 1).is_err());
+        assert!(new_day(&root, 2025, 26).is_err());
+
+        assert_eq!(
+            "pub mod solution;\npub mod year2024;\npub mod year2025;\n\npub use solution::Solver;\n",
+            read(&root.join("src/lib.rs")).unwrap()
+        );
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\n",
+            read(&root.join("src/year2025/mod.rs")).unwrap()
+        );
+        assert_eq!(
+            render(2025, 1),
+            read(&root.join("src/year2025/day01.rs")).unwrap()
+        );
+        assert_eq!(
+            "",
+            read(&root.join("data/input/year2025/day02.txt")).unwrap()
+        );
+        assert!(root.join("data/test/year2025/day02.expected").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,484 @@
+// a small http server that solves the input posted to /{year}/{day}, answering with the same json
+// object as `run --format json`
+//
+// parameters of the solver are given in the query, like /2024/14?width=11&height=7
+//
+// pages in a browser may only post to the server from the origin it was started with, and the
+// number of connections, the size of the requests and the time spent on them are limited
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use rust_aoc::{
+    input::normalize,
+    report::{json_string, Failure, Report},
+    runner::{isolate, with_timeout},
+    Overrides,
+};
+
+// inputs are a few kilobytes, anything much larger is not meant for this server
+const MAX_BODY: usize = 1 << 20;
+const MAX_HEAD: u64 = 1 << 13;
+const MAX_CONNECTIONS: usize = 8;
+
+// how long a client may take to send its request or read the answer
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    // the page that may post inputs from a browser, pages of any other origin are refused
+    pub origin: Option<String>,
+    // how long a day may take before it is answered with a timeout
+    pub timeout: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Request {
+    method: String,
+    target: String,
+    // where a browser sent the request from, other clients do not tell
+    origin: Option<String>,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+fn error(status: u16, message: &str) -> Response {
+    Response {
+        status,
+        body: format!("{{\"error\":{}}}", json_string(message)),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let malformed = || error(400, "malformed request");
+
+    // the request line and the headers end with an empty line, which has to come early enough
+    let mut head = reader.by_ref().take(MAX_HEAD);
+
+    let mut line = String::new();
+    head.read_line(&mut line).map_err(|_| malformed())?;
+
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(malformed());
+    };
+
+    let mut length = 0;
+    let mut origin = None;
+
+    loop {
+        let mut header = String::new();
+        head.read_line(&mut header).map_err(|_| malformed())?;
+
+        if !header.ends_with('\n') {
+            return Err(malformed());
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().map_err(|_| malformed())?;
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    if length > MAX_BODY {
+        return Err(error(413, "the input is too large"));
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|_| malformed())?;
+
+    Ok(Request {
+        method: method.to_string(),
+        target: target.to_string(),
+        origin,
+        body,
+    })
+}
+
+// undoes the escapes of urls, like %32 for 2 and + for a space
+fn decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut it = s.bytes();
+
+    while let Some(b) = it.next() {
+        match b {
+            b'%' => {
+                let hex = [it.next()?, it.next()?];
+
+                if !hex.iter().all(u8::is_ascii_hexdigit) {
+                    return None;
+                }
+
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            b'+' => bytes.push(b' '),
+            b => bytes.push(b),
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+fn parse_target(target: &str) -> Option<(u32, u32, Overrides)> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (year, day) = path.trim_matches('/').split_once('/')?;
+
+    let mut overrides = Overrides::default();
+
+    for param in query.split('&').filter(|p| !p.is_empty()) {
+        let (name, value) = param.split_once('=')?;
+        overrides.set(&decode(name)?, &decode(value)?);
+    }
+
+    Some((
+        decode(year)?.parse().ok()?,
+        decode(day)?.parse().ok()?,
+        overrides,
+    ))
+}
+
+// `slot` is held until the solver finishes, which may be long after the request timed out
+fn route(request: &Request, settings: &Settings, slot: impl Send + 'static) -> Response {
+    // any page could post to localhost, only the configured one is allowed to
+    if request.origin.is_some() && request.origin != settings.origin {
+        return error(403, "posting from this origin is not allowed");
+    }
+
+    // browsers ask before posting json from another origin
+    if request.method == "OPTIONS" {
+        return Response {
+            status: 204,
+            body: String::new(),
+        };
+    }
+
+    let Some((year, day, overrides)) = parse_target(&request.target) else {
+        return error(404, "expected a path like /2024/1");
+    };
+
+    if request.method != "POST" {
+        return error(405, "post the input of the day");
+    }
+
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return error(400, "the input is not valid utf-8");
+    };
+
+    let result = match rust_aoc::find(year, day) {
+        Some(solver) => {
+            let input = normalize(input).0;
+
+            let solved = with_timeout(Some(settings.timeout), move || {
+                let _slot = slot;
+
+                isolate(|| solver.solve_timed(&input, &overrides))
+            });
+
+            match solved {
+                Some(solved) => solved
+                    .map_err(|p| Failure::Error(p.to_string()))
+                    .and_then(|r| r.map_err(|e| Failure::Error(e.to_string()))),
+                None => Err(Failure::TimedOut(settings.timeout)),
+            }
+        }
+        None => Err(Failure::Unimplemented(format!(
+            "{} day {} is not implemented",
+            year, day
+        ))),
+    };
+
+    let report = Report { year, day, result };
+
+    let status = match report.result {
+        Ok(_) => 200,
+        Err(Failure::Unimplemented(_)) => 404,
+        Err(Failure::Error(_)) => 400,
+        Err(Failure::TimedOut(_)) => 503,
+    };
+
+    Response {
+        status,
+        body: report.to_json(),
+    }
+}
+
+fn write_response(
+    stream: &mut impl Write,
+    response: &Response,
+    origin: Option<&str>,
+) -> io::Result<()> {
+    // without an origin browsers do not let any page read the answers
+    let cors = origin.map_or(String::new(), |origin| {
+        format!(
+            "Access-Control-Allow-Origin: {}\r\nAccess-Control-Allow-Methods: POST, OPTIONS\r\n\
+             Access-Control-Allow-Headers: Content-Type\r\n",
+            origin
+        )
+    });
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}\
+         Connection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        cors,
+        response.body
+    )
+}
+
+// one of the connections being answered, given back when dropped
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn take(open: &Arc<AtomicUsize>) -> Option<Slot> {
+        open.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+            (n < MAX_CONNECTIONS).then_some(n + 1)
+        })
+        .ok()
+        .map(|_| Slot(open.clone()))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handle(mut stream: TcpStream, settings: &Settings, slot: Option<Slot>) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let response = match slot {
+        Some(slot) => match read_request(&mut BufReader::new(stream.try_clone()?)) {
+            Ok(request) => route(&request, settings, slot),
+            Err(response) => response,
+        },
+        None => error(503, "too many connections, try again later"),
+    };
+
+    write_response(&mut stream, &response, settings.origin.as_deref())
+}
+
+// answers every connection on its own thread, so that a slow day does not block the others
+pub fn serve(listener: TcpListener, settings: Settings) {
+    let open = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming().flatten() {
+        let settings = settings.clone();
+
+        match Slot::take(&open) {
+            Some(slot) => {
+                thread::spawn(move || handle(stream, &settings, Some(slot)));
+            }
+            // refused right away, without waiting for the request
+            None => {
+                let _ = handle(stream, &settings, None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(target: &str, body: &str) -> Request {
+        Request {
+            method: "POST".to_string(),
+            target: target.to_string(),
+            origin: None,
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            origin: Some("http://localhost:3000".to_string()),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    fn send(address: std::net::SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "{}", request).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /2024/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 6\r\n\
+                   Origin: http://localhost:3000\r\n\r\n3   4\nignored";
+
+        assert_eq!(
+            Ok(Request {
+                origin: Some("http://localhost:3000".to_string()),
+                ..post("/2024/1", "3   4\n")
+            }),
+            read_request(&mut raw.as_bytes())
+        );
+        assert_eq!(
+            400,
+            read_request(&mut "\r\n".as_bytes()).unwrap_err().status
+        );
+
+        let endless = format!("POST /2024/1 HTTP/1.1\r\nHost: {}", "x".repeat(1 << 14));
+        assert_eq!(
+            400,
+            read_request(&mut endless.as_bytes()).unwrap_err().status
+        );
+
+        let large = format!(
+            "POST /2024/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            1 << 21
+        );
+        assert_eq!(413, read_request(&mut large.as_bytes()).unwrap_err().status);
+    }
+
+    #[test]
+    fn test_parse_target() {
+        let mut overrides = Overrides::default();
+        overrides.set("width", "11");
+
+        assert_eq!(
+            Some((2024, 14, overrides.clone())),
+            parse_target("/2024/14?width=11")
+        );
+        assert_eq!(
+            Some((2024, 14, overrides)),
+            parse_target("/%32024/1%34?wid%74h=%311")
+        );
+        assert_eq!(Some("a b/c".to_string()), decode("a+b%2Fc"));
+        assert_eq!(None, decode("%3"));
+        assert_eq!(None, decode("%zz"));
+        assert_eq!(None, decode("%+1"));
+        assert_eq!(None, decode("%ff"));
+        assert_eq!(None, parse_target("/2024/1?width=%1"));
+    }
+
+    #[test]
+    fn test_route() {
+        let response = route(&post("/2024/1", "3   4\r\n4   3\r\n"), &settings(), ());
+
+        assert_eq!(200, response.status);
+        assert!(response.body.starts_with(
+            "{\"year\":2024,\"day\":1,\"status\":\"ok\",\"part_one\":0,\"part_two\":7,"
+        ));
+
+        let route = |request: &Request| route(request, &settings(), ());
+
+        let response = route(&post("/2024/14?width=11&height=7", "p=0,4 v=3,-3\n"));
+        assert_eq!(200, response.status);
+
+        assert_eq!(400, route(&post("/2024/14?depth=1", "")).status);
+        assert_eq!(400, route(&post("/2024/1", "3 4\n")).status);
+        assert_eq!(404, route(&post("/2024/26", "")).status);
+        assert_eq!(404, route(&post("/2024", "")).status);
+
+        let get = Request {
+            method: "GET".to_string(),
+            ..post("/2024/1", "")
+        };
+        assert_eq!(405, route(&get).status);
+
+        let foreign = Request {
+            origin: Some("https://example.com".to_string()),
+            ..post("/2024/1", "3   4\n")
+        };
+        assert_eq!(403, route(&foreign).status);
+    }
+
+    #[test]
+    fn test_route_timeout() {
+        let settings = Settings {
+            timeout: Duration::ZERO,
+            ..settings()
+        };
+        let input = "p=0,4 v=3,-3\n".repeat(500);
+
+        let response = route(&post("/2024/14", &input), &settings, ());
+
+        assert_eq!(503, response.status);
+        assert!(response.body.contains("\"status\":\"timeout\""));
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || serve(listener, settings()));
+
+        let response = send(
+            address,
+            "POST /2024/1 HTTP/1.1\r\nContent-Length: 12\r\n\r\n3   4\n4   3\n",
+        );
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Access-Control-Allow-Origin: http://localhost:3000\r\n"));
+        assert!(response.contains("\"part_one\":0,\"part_two\":7"));
+
+        // connections that never send their request use up the slots
+        let idle: Vec<TcpStream> = (0..MAX_CONNECTIONS)
+            .map(|_| TcpStream::connect(address).unwrap())
+            .collect();
+
+        let response = send(address, "");
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+
+        drop(idle);
+    }
+
+    #[test]
+    fn test_slots() {
+        let open = Arc::new(AtomicUsize::new(0));
+        let slots: Vec<Slot> = (0..MAX_CONNECTIONS)
+            .map(|_| Slot::take(&open).unwrap())
+            .collect();
+
+        assert!(Slot::take(&open).is_none());
+        drop(slots);
+        assert_eq!(0, open.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,331 @@
+// common interface of all puzzle solutions and the registry that collects them
+
+use std::{
+    fmt,
+    ops::RangeBounds,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+pub use crate::{answer::Answer, util::parse::ParseError};
+
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    // the parsed input is allowed to borrow from the raw puzzle input
+    type Input<'a>;
+
+    // the values the puzzle leaves open, `()` for puzzles without any
+    type Params: Parameters;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_one(input: &Self::Input<'_>, params: &Self::Params) -> Answer;
+    fn part_two(input: &Self::Input<'_>, params: &Self::Params) -> Answer;
+}
+
+// values like the size of the room in 2024 day 14, which differ between the examples and the
+// real inputs, the defaults are the ones of the real inputs
+pub trait Parameters: Default {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+impl Parameters for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(unknown_parameter(name))
+    }
+}
+
+pub fn unknown_parameter(name: &str) -> String {
+    format!("unknown parameter {}", name)
+}
+
+pub fn parameter<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value.parse().or(Err(format!(
+        "invalid value {} for parameter {}",
+        value, name
+    )))
+}
+
+// like `parameter`, for values that only make sense in `range`, like a width of at least 1
+pub fn parameter_in<T: FromStr + PartialOrd>(
+    name: &str,
+    value: &str,
+    range: impl RangeBounds<T>,
+) -> Result<T, String> {
+    let res = parameter(name, value)?;
+
+    if range.contains(&res) {
+        Ok(res)
+    } else {
+        Err(format!(
+            "value {} is out of range for parameter {}",
+            value, name
+        ))
+    }
+}
+
+// parameters set by name, from `--param width=11` or the [params] section of a sidecar
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides {
+    values: Vec<(String, String)>,
+}
+
+impl Overrides {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.push((name.to_string(), value.to_string()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // the defaults of `P` with the overrides applied in order
+    pub fn apply<P: Parameters>(&self) -> Result<P, String> {
+        let mut params = P::default();
+
+        for (name, value) in &self.values {
+            params.set(name, value)?;
+        }
+
+        Ok(params)
+    }
+}
+
+// why a day could not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Unimplemented { year: u32, day: u32 },
+    Params(String),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unimplemented { year, day } => {
+                write!(f, "{} day {} is not implemented", year, day)
+            }
+            Error::Params(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "could not parse the input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+// a parsed input with the type erased, so that solvers of all days can be stored together
+pub trait Parsed {
+    fn part_one(&self) -> Answer;
+    fn part_two(&self) -> Answer;
+}
+
+struct Prepared<'a, S: Solution> {
+    input: S::Input<'a>,
+    params: S::Params,
+}
+
+impl<S: Solution> Parsed for Prepared<'_, S> {
+    fn part_one(&self) -> Answer {
+        S::part_one(&self.input, &self.params)
+    }
+
+    fn part_two(&self) -> Answer {
+        S::part_two(&self.input, &self.params)
+    }
+}
+
+fn parse_boxed<'a, S: Solution + 'static>(
+    input: &'a str,
+    overrides: &Overrides,
+) -> Result<Box<dyn Parsed + 'a>, Error> {
+    let params = overrides.apply().map_err(Error::Params)?;
+    let input = S::parse(input)?;
+
+    Ok(Box::new(Prepared::<S> { input, params }))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+    // the peak heap memory in bytes, when it was measured
+    pub peak_memory: Option<usize>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+
+    (res, start.elapsed())
+}
+
+type ParseFn = for<'a> fn(&'a str, &Overrides) -> Result<Box<dyn Parsed + 'a>, Error>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    parse: ParseFn,
+}
+
+impl Solver {
+    pub const fn new<S: Solution + 'static>() -> Self {
+        Solver {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    pub fn parse<'a>(
+        &self,
+        input: &'a str,
+        overrides: &Overrides,
+    ) -> Result<Box<dyn Parsed + 'a>, Error> {
+        (self.parse)(input, overrides)
+    }
+
+    // solves with the parameters of the real inputs
+    pub fn solve(&self, input: &str) -> Result<(Answer, Answer), Error> {
+        self.solve_with(input, &Overrides::default())
+    }
+
+    pub fn solve_with(
+        &self,
+        input: &str,
+        overrides: &Overrides,
+    ) -> Result<(Answer, Answer), Error> {
+        let parsed = self.parse(input, overrides)?;
+
+        Ok((parsed.part_one(), parsed.part_two()))
+    }
+
+    // like `solve_with`, but measures parsing and both parts separately
+    pub fn solve_timed(
+        &self,
+        input: &str,
+        overrides: &Overrides,
+    ) -> Result<(Answer, Answer, Timings), Error> {
+        let (parsed, parse) = timed(|| self.parse(input, overrides));
+        let parsed = parsed?;
+        let (part1, part_one) = timed(|| parsed.part_one());
+        let (part2, part_two) = timed(|| parsed.part_two());
+
+        Ok((
+            part1,
+            part2,
+            Timings {
+                parse,
+                part_one,
+                part_two,
+                peak_memory: None,
+            },
+        ))
+    }
+}
+
+// iterate the solvers of all years, ordered by year and day
+pub fn solvers() -> impl Iterator<Item = &'static Solver> {
+    crate::YEARS.iter().flat_map(|year| year.iter())
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Solver> {
+    solvers().find(|s| s.year == year && s.day == day)
+}
+
+// solves a day from the text of its input, for using the solvers without any files
+pub fn solve(year: u32, day: u32, input: &str) -> Result<(String, String), Error> {
+    let solver = find(year, day).ok_or(Error::Unimplemented { year, day })?;
+    let (input, _) = crate::input::normalize(input);
+    let (part1, part2) = solver.solve(&input)?;
+
+    Ok((part1.to_string(), part2.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_ordered_and_unique() {
+        let keys: Vec<_> = solvers().map(|s| (s.year, s.day)).collect();
+
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(keys.iter().all(|(_, day)| (1..=25).contains(day)));
+    }
+
+    #[test]
+    fn test_find() {
+        let solver = find(2024, 1).expect("day is registered");
+
+        assert_eq!((2024, 1), (solver.year, solver.day));
+        assert!(find(2024, 26).is_none());
+    }
+
+    #[test]
+    fn test_parse_errors_are_returned() {
+        let solver = find(2024, 6).expect("day is registered");
+        let Err(Error::Parse(error)) = solver.solve("..#\n.x^\n") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!((2, 2, "x"), (error.line, error.column, error.text.as_str()));
+        assert!(solver.solve_timed("", &Overrides::default()).is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            Ok(("0".to_string(), "8".to_string())),
+            solve(2024, 1, "3   4\r\n4   3\r\n1   1\r\n")
+        );
+        assert_eq!(
+            Err(Error::Unimplemented {
+                year: 2024,
+                day: 26
+            }),
+            solve(2024, 26, "")
+        );
+        assert!(matches!(solve(2024, 1, "3 4"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let mut overrides = Overrides::default();
+
+        assert_eq!(Ok(()), overrides.apply::<()>());
+
+        overrides.set("width", "11");
+
+        assert_eq!(
+            Err("unknown parameter width".to_string()),
+            overrides.apply::<()>()
+        );
+        assert_eq!(
+            Err("invalid value x for parameter width".to_string()),
+            parameter::<i64>("width", "x")
+        );
+        assert_eq!(Ok(11), parameter_in::<i64>("width", "11", 1..));
+        assert_eq!(
+            Err("value 0 is out of range for parameter width".to_string()),
+            parameter_in::<i64>("width", "0", 1..)
+        );
+    }
+}
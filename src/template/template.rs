@@ -1,50 +1,38 @@
-type Input = todo!();
+use crate::solution::{Answer, ParseError, Solution};
 
-pub fn parse(input: &str) -> Input {}
+type Input = Vec<String>;
 
-pub fn solve_part_one(input: &Input) -> usize {
-    todo!()
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Ok(input.lines().map(String::from).collect())
 }
 
-pub fn solve_part_two(input: &Input) -> usize {
-    todo!()
+pub fn solve_part_one(_input: &Input) -> Answer {
+    Answer::Unsolved
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
+pub fn solve_part_two(_input: &Input) -> Answer {
+    Answer::Unsolved
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year20XX/dayXX.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 20XX;
+    const DAY: u32 = XX;
+    const TITLE: &'static str = "";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(0, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input)
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input)
     }
 }
@@ -0,0 +1,145 @@
+// debug output of the solvers, written to stderr so that stdout only holds the results
+//
+// nothing is printed unless a level is set with --verbose or the RUST_AOC_LOG environment
+// variable, like RUST_AOC_LOG=trace, and the arguments of disabled messages are not evaluated
+//
+//     aoc_debug!("num: {num}, len: {}", l);
+//
+// the macros are prefixed so that they do not clash with the ones of log or tracing
+
+use std::{
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use once_cell::sync::Lazy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    // for output as large as whole grids
+    Trace,
+}
+
+const LEVELS: [Level; 4] = [Level::Off, Level::Info, Level::Debug, Level::Trace];
+
+impl FromStr for Level {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err("log level not recognized"),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+// an unknown level in the environment is ignored rather than failing every run
+static LEVEL: Lazy<AtomicU8> = Lazy::new(|| {
+    let level = std::env::var("RUST_AOC_LOG")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(Level::Off);
+
+    AtomicU8::new(level as u8)
+});
+
+pub fn level() -> Level {
+    LEVELS[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    allows(self::level(), level)
+}
+
+// whether a message of `level` is printed when the log level is `max`
+fn allows(max: Level, level: Level) -> bool {
+    level != Level::Off && level <= max
+}
+
+pub fn log(level: Level, module: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level, module, args);
+}
+
+#[macro_export]
+macro_rules! aoc_info {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Info) {
+            $crate::trace::log($crate::trace::Level::Info, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            $crate::trace::log($crate::trace::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! aoc_trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            $crate::trace::log($crate::trace::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Ok(Level::Trace), "trace".parse());
+        assert!("verbose".parse::<Level>().is_err());
+
+        for level in LEVELS {
+            assert_eq!(Ok(level), level.to_string().parse());
+        }
+
+        assert!(Level::Info < Level::Debug && Level::Debug < Level::Trace);
+    }
+
+    // the level is global, so the tests only read it while others may be logging
+    #[test]
+    fn test_enabled() {
+        assert!(allows(Level::Debug, Level::Info) && allows(Level::Debug, Level::Debug));
+        assert!(!allows(Level::Debug, Level::Trace) && !allows(Level::Debug, Level::Off));
+        assert!(!allows(Level::Off, Level::Info));
+
+        // disabled messages do not evaluate their arguments
+        let mut evaluated = false;
+        aoc_trace!("{}", {
+            evaluated = true;
+            1
+        });
+        assert_eq!(enabled(Level::Trace), evaluated);
+    }
+}
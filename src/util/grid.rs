@@ -3,7 +3,7 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use crate::util::point::Point;
+use crate::util::{parse::ParseError, point::Point};
 
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
@@ -76,7 +76,7 @@ impl<T> Grid<T> {
         self.elements
             .iter()
             .position(predicate)
-            .and_then(|i| Some(self.point_from_index(i)))
+            .map(|i| self.point_from_index(i))
     }
 
     pub fn find_all(&self, predicate: impl Fn(&T) -> bool) -> Vec<Point> {
@@ -95,7 +95,11 @@ impl<T> Grid<T> {
         self.elements.len()
     }
 
-    pub fn iter(&self) -> GridIterator<T> {
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn iter(&self) -> GridIterator<'_, T> {
         GridIterator { grid: self, n: 0 }
     }
 }
@@ -139,20 +143,26 @@ impl<T: Default + Copy> Grid<T> {
 }
 
 impl<T: Debug> Grid<T> {
-    pub fn print(&self) {
+    // the grid as rows of debug formatted elements, for the debug output of the solvers
+    pub fn render(&self) -> String {
+        let mut res = String::new();
+
         for j in 0..self.size.y {
             for i in 0..self.size.x {
-                print!(
+                res += &format!(
                     "{:?}",
                     self.elements[self.calculate_index(&Point::new(i, j))]
                 );
             }
-            println!();
+            res.push('\n');
         }
+
+        res
     }
 }
 
 impl Grid<u8> {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         let lines_as_bytes: Vec<_> = s.lines().map(|s| s.as_bytes()).collect();
 
@@ -171,15 +181,33 @@ impl Grid<u8> {
         }
     }
 
-    pub fn print_chars(&self) {
-        for j in 0..self.size.y {
-            for i in 0..self.size.x {
-                print!(
-                    "{}",
-                    self.elements[self.calculate_index(&Point::new(i, j))] as char
-                );
-            }
-            println!();
+    // like `from_str`, but rejects empty input and rows of different widths
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut lines = s.lines();
+        let width = match lines.next() {
+            Some(first) if !first.is_empty() => first.len(),
+            _ => return Err(ParseError::at(s, s, "expected a grid")),
+        };
+
+        if let Some(line) = lines.find(|line| line.len() != width) {
+            return Err(ParseError::at(
+                s,
+                line,
+                format!("expected a row of width {}", width),
+            ));
+        }
+
+        Ok(Grid::from_str(s))
+    }
+
+    pub fn render_chars(&self) -> String {
+        let mut res = String::with_capacity(self.elements.len() + self.size.y as usize);
+
+        for row in self.elements.chunks(self.size.x.max(1) as usize) {
+            res.extend(row.iter().map(|&b| b as char));
+            res.push('\n');
         }
+
+        res
     }
 }
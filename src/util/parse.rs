@@ -1,4 +1,80 @@
-use std::str::Bytes;
+use std::{
+    error::Error,
+    fmt,
+    str::{Bytes, FromStr},
+};
+
+// where in the puzzle input parsing failed, lines and columns start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // `text` has to be a slice of `input`, the error points at its first character
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // for input that stops before everything was read
+    pub fn end(input: &str, message: impl Into<String>) -> Self {
+        let trimmed = input.trim_end();
+
+        ParseError::at(input, &trimmed[trimmed.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " in {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+// parses `text`, a slice of `input`, into any type that implements `FromStr`
+pub fn parse_at<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| {
+        let name = std::any::type_name::<T>();
+
+        ParseError::at(input, text, format!("expected a value of type {}", name))
+    })
+}
+
+pub fn split_once_at<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {:?}", delimiter)))
+}
 
 pub struct ParseSigned<'a> {
     bytes: Bytes<'a>,
@@ -53,6 +129,21 @@ impl Iterator for ParseSigned<'_> {
     }
 }
 
+// fails on the first character of `text` that is neither whitespace nor one of `allowed`
+pub fn check_chars(input: &str, text: &str, allowed: &str) -> Result<(), ParseError> {
+    match text
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace() && !allowed.contains(*c))
+    {
+        Some((i, c)) => Err(ParseError::at(
+            input,
+            &text[i..i + c.len_utf8()],
+            "unexpected character",
+        )),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,7 +157,30 @@ mod tests {
         }
         .collect();
 
-        dbg!(&parsed);
         assert_eq!(parsed, vec![-13, 23, -3, 2, 25])
     }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1 2\n3 x4\n";
+
+        let error = parse_at::<u32>(input, &input[6..8]).unwrap_err();
+        assert_eq!(
+            (2, 3, "x4"),
+            (error.line, error.column, error.text.as_str())
+        );
+        assert_eq!(
+            "line 2, column 3: expected a value of type u32 in \"x4\"",
+            error.to_string()
+        );
+
+        let error = split_once_at(input, &input[..3], ",").unwrap_err();
+        assert_eq!((1, 1), (error.line, error.column));
+
+        let error = check_chars(input, input, "0123456789 ").unwrap_err();
+        assert_eq!((2, 3, "x"), (error.line, error.column, error.text.as_str()));
+
+        let error = ParseError::end(input, "missing a line");
+        assert_eq!((2, 5, ""), (error.line, error.column, error.text.as_str()));
+    }
 }
@@ -1,119 +1,233 @@
-use std::ops::{Add, AddAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::{
+    hash::Hash,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+// the integer types a point can have as coordinates
+pub trait Coordinate:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Rem<Output = Self>
+    + RemAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+// coordinates that can point up and left
+pub trait Signed: Coordinate + Neg<Output = Self> {
+    const MINUS_ONE: Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                const MINUS_ONE: Self = -1;
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+signed!(i8, i16, i32, i64, i128, isize);
 
-// TODO: make this generic for different integer types
-pub struct Point {
-    pub x: i64,
-    pub y: i64,
+// the distance between two coordinates, without overflowing unsigned types
+fn distance<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
 }
 
-pub const ORIGIN: Point = Point::new(0, 0);
-pub const UP: Point = Point::new(0, -1);
-pub const DOWN: Point = Point::new(0, 1);
-pub const LEFT: Point = Point::new(-1, 0);
-pub const RIGHT: Point = Point::new(1, 0);
+// without a type, like in `Point`, the coordinates are i64
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy, Default)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
 
-pub const CARDINALS: [Point; 4] = [UP, DOWN, LEFT, RIGHT];
+// the directions of the i64 points, the other types have them as associated constants
+pub const ORIGIN: Point = Point::ORIGIN;
+pub const UP: Point = Point::UP;
+pub const DOWN: Point = Point::DOWN;
+pub const LEFT: Point = Point::LEFT;
+pub const RIGHT: Point = Point::RIGHT;
 
-pub const UP_LEFT: Point = Point::new(-1, -1);
-pub const UP_RIGHT: Point = Point::new(1, -1);
-pub const DOWN_LEFT: Point = Point::new(-1, 1);
-pub const DOWN_RIGHT: Point = Point::new(1, 1);
+pub const CARDINALS: [Point; 4] = Point::CARDINALS;
 
-pub const NEIGHBORS: [Point; 8] = [
-    UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT,
-];
+pub const UP_LEFT: Point = Point::UP_LEFT;
+pub const UP_RIGHT: Point = Point::UP_RIGHT;
+pub const DOWN_LEFT: Point = Point::DOWN_LEFT;
+pub const DOWN_RIGHT: Point = Point::DOWN_RIGHT;
 
-impl Point {
-    pub const fn new(x: i64, y: i64) -> Point {
+pub const NEIGHBORS: [Point; 8] = Point::NEIGHBORS;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Self = Point::new(T::ZERO, T::ZERO);
 
-    pub fn manhattan(&self, other: &Point) -> i64 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    pub fn manhattan(&self, other: &Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
     }
 
+    // the number of king moves between the points
+    pub fn chebyshev(&self, other: &Self) -> T {
+        distance(self.x, other.x).max(distance(self.y, other.y))
+    }
+
+    // a point with coordinates of a type that holds every value of `T`, like i32 to i64
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(self.x.into(), self.y.into())
+    }
+
+    // a point with coordinates of another type, None when a coordinate does not fit
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl<T: Signed> Point<T> {
+    pub const UP: Self = Point::new(T::ZERO, T::MINUS_ONE);
+    pub const DOWN: Self = Point::new(T::ZERO, T::ONE);
+    pub const LEFT: Self = Point::new(T::MINUS_ONE, T::ZERO);
+    pub const RIGHT: Self = Point::new(T::ONE, T::ZERO);
+
+    pub const CARDINALS: [Self; 4] = [Self::UP, Self::DOWN, Self::LEFT, Self::RIGHT];
+
+    pub const UP_LEFT: Self = Point::new(T::MINUS_ONE, T::MINUS_ONE);
+    pub const UP_RIGHT: Self = Point::new(T::ONE, T::MINUS_ONE);
+    pub const DOWN_LEFT: Self = Point::new(T::MINUS_ONE, T::ONE);
+    pub const DOWN_RIGHT: Self = Point::new(T::ONE, T::ONE);
+
+    pub const NEIGHBORS: [Self; 8] = [
+        Self::UP,
+        Self::UP_RIGHT,
+        Self::RIGHT,
+        Self::DOWN_RIGHT,
+        Self::DOWN,
+        Self::DOWN_LEFT,
+        Self::LEFT,
+        Self::UP_LEFT,
+    ];
+
     pub fn from_u8(char: u8) -> Self {
         match char {
-            b'^' => UP,
-            b'v' => DOWN,
-            b'<' => LEFT,
-            b'>' => RIGHT,
+            b'^' => Self::UP,
+            b'v' => Self::DOWN,
+            b'<' => Self::LEFT,
+            b'>' => Self::RIGHT,
             _ => panic!("character not recognized"),
         }
     }
 }
 
-impl Add for Point {
-    type Output = Point;
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
         Point::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
 
-impl AddAssign for Point {
+impl<T: Coordinate> AddAssign for Point<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl Sub for Point {
-    type Output = Point;
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
         Point::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
-impl SubAssign for Point {
+impl<T: Coordinate> SubAssign for Point<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
-impl Mul<i64> for Point {
-    type Output = Point;
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Self;
 
-    fn mul(self, rhs: i64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Point::new(self.x * rhs, self.y * rhs)
     }
 }
 
-impl MulAssign<i64> for Point {
-    fn mul_assign(&mut self, rhs: i64) {
+impl<T: Coordinate> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
     }
 }
 
-impl Rem<i64> for Point {
-    type Output = Point;
+impl<T: Coordinate> Rem<T> for Point<T> {
+    type Output = Self;
 
-    fn rem(self, rhs: i64) -> Self::Output {
+    fn rem(self, rhs: T) -> Self::Output {
         Point::new(self.x % rhs, self.y % rhs)
     }
 }
 
-impl RemAssign<i64> for Point {
-    fn rem_assign(&mut self, rhs: i64) {
+impl<T: Coordinate> RemAssign<T> for Point<T> {
+    fn rem_assign(&mut self, rhs: T) {
         self.x %= rhs;
         self.y %= rhs;
     }
 }
 
-impl Rem<Point> for Point {
-    type Output = Point;
+impl<T: Coordinate> Rem<Point<T>> for Point<T> {
+    type Output = Self;
 
-    fn rem(self, rhs: Point) -> Self::Output {
+    fn rem(self, rhs: Point<T>) -> Self::Output {
         Point::new(self.x % rhs.x, self.y % rhs.y)
     }
 }
 
-impl PartialOrd for Point {
+impl<T: Coordinate> PartialOrd for Point<T> {
     fn gt(&self, other: &Self) -> bool {
         self.x > other.x && self.y > other.y
     }
@@ -127,10 +241,450 @@ impl PartialOrd for Point {
             return Some(std::cmp::Ordering::Greater);
         }
 
-        if other.gt(&self) {
+        if other.gt(self) {
             return Some(std::cmp::Ordering::Less);
         }
 
         None
     }
 }
+
+// a point in space, like a voxel of a droplet or a brick
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy, Default)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// the 26 points around the origin, with x changing fastest
+const fn neighbors_3d<T: Copy>(units: [T; 3]) -> [Point3<T>; 26] {
+    let mut res = [Point3::new(units[1], units[1], units[1]); 26];
+    let (mut i, mut n) = (0, 0);
+
+    while i < 27 {
+        // the 13th combination is the origin itself
+        if i != 13 {
+            res[n] = Point3::new(units[i % 3], units[i / 3 % 3], units[i / 9]);
+            n += 1;
+        }
+
+        i += 1;
+    }
+
+    res
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Self = Point3::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y) + distance(self.z, other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        distance(self.x, other.x)
+            .max(distance(self.y, other.y))
+            .max(distance(self.z, other.z))
+    }
+
+    pub fn cast<U: From<T>>(self) -> Point3<U> {
+        Point3::new(self.x.into(), self.y.into(), self.z.into())
+    }
+
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point3<U>> {
+        Some(Point3::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    // the points sharing a face with the origin
+    pub const CARDINALS: [Self; 6] = [
+        Point3::new(T::MINUS_ONE, T::ZERO, T::ZERO),
+        Point3::new(T::ONE, T::ZERO, T::ZERO),
+        Point3::new(T::ZERO, T::MINUS_ONE, T::ZERO),
+        Point3::new(T::ZERO, T::ONE, T::ZERO),
+        Point3::new(T::ZERO, T::ZERO, T::MINUS_ONE),
+        Point3::new(T::ZERO, T::ZERO, T::ONE),
+    ];
+
+    // the points sharing a face, an edge or a corner with the origin
+    pub const NEIGHBORS: [Self; 26] = neighbors_3d([T::MINUS_ONE, T::ZERO, T::ONE]);
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coordinate> MulAssign<T> for Point3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T: Coordinate> Rem<T> for Point3<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Point3::new(self.x % rhs, self.y % rhs, self.z % rhs)
+    }
+}
+
+impl<T: Coordinate> RemAssign<T> for Point3<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        self.x %= rhs;
+        self.y %= rhs;
+        self.z %= rhs;
+    }
+}
+
+impl<T: Coordinate> Rem<Point3<T>> for Point3<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Point3<T>) -> Self::Output {
+        Point3::new(self.x % rhs.x, self.y % rhs.y, self.z % rhs.z)
+    }
+}
+
+// a point with any number of dimensions, like the 4d points of a hypercube
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+pub struct PointN<const N: usize, T = i64> {
+    pub coords: [T; N],
+}
+
+impl<const N: usize, T> PointN<N, T> {
+    pub const fn new(coords: [T; N]) -> Self {
+        PointN { coords }
+    }
+}
+
+impl<const N: usize, T: Coordinate> PointN<N, T> {
+    pub const ORIGIN: Self = PointN::new([T::ZERO; N]);
+
+    // applies `f` to the coordinates of both points, dimension by dimension
+    fn zip(mut self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        for (a, b) in self.coords.iter_mut().zip(rhs.coords) {
+            *a = f(*a, b);
+        }
+
+        self
+    }
+
+    fn distances(&self, other: &Self) -> impl Iterator<Item = T> + '_ {
+        let other = other.coords;
+
+        self.coords.iter().zip(other).map(|(&a, b)| distance(a, b))
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.distances(other).fold(T::ZERO, |sum, d| sum + d)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.distances(other).max().unwrap_or(T::ZERO)
+    }
+}
+
+impl<const N: usize, T: Signed> PointN<N, T> {
+    // the 2 * N points sharing a face with the origin
+    pub fn cardinals() -> impl Iterator<Item = Self> {
+        (0..N).flat_map(|i| {
+            [T::MINUS_ONE, T::ONE].map(|d| {
+                let mut p = Self::ORIGIN;
+                p.coords[i] = d;
+                p
+            })
+        })
+    }
+
+    // the 3^N - 1 points touching the origin, with the first coordinate changing fastest
+    pub fn neighbors() -> impl Iterator<Item = Self> {
+        let units = [T::MINUS_ONE, T::ZERO, T::ONE];
+
+        // the digits in base 3 of every number below 3^N pick the coordinates of a point
+        (0..3usize.pow(N as u32)).filter_map(move |mut i| {
+            let mut p = Self::ORIGIN;
+
+            for coord in &mut p.coords {
+                *coord = units[i % 3];
+                i /= 3;
+            }
+
+            (p != Self::ORIGIN).then_some(p)
+        })
+    }
+}
+
+impl<const N: usize, T: Coordinate> Default for PointN<N, T> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for PointN<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        PointN::new(coords)
+    }
+}
+
+impl<T> From<Point<T>> for PointN<2, T> {
+    fn from(p: Point<T>) -> Self {
+        PointN::new([p.x, p.y])
+    }
+}
+
+impl<T> From<Point3<T>> for PointN<3, T> {
+    fn from(p: Point3<T>) -> Self {
+        PointN::new([p.x, p.y, p.z])
+    }
+}
+
+impl<const N: usize, T> Index<usize> for PointN<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coords[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for PointN<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.coords[index]
+    }
+}
+
+impl<const N: usize, T: Coordinate> Add for PointN<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize, T: Coordinate> AddAssign for PointN<N, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Sub for PointN<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize, T: Coordinate> SubAssign for PointN<N, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Mul<T> for PointN<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        PointN::new(self.coords.map(|c| c * rhs))
+    }
+}
+
+impl<const N: usize, T: Coordinate> MulAssign<T> for PointN<N, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Rem<T> for PointN<N, T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        PointN::new(self.coords.map(|c| c % rhs))
+    }
+}
+
+impl<const N: usize, T: Coordinate> RemAssign<T> for PointN<N, T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Rem<PointN<N, T>> for PointN<N, T> {
+    type Output = Self;
+
+    fn rem(self, rhs: PointN<N, T>) -> Self::Output {
+        self.zip(rhs, |a, b| a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(UP, Point::<i64>::UP);
+        assert_eq!(NEIGHBORS, Point::<i64>::NEIGHBORS);
+        assert_eq!(Point::new(-1i8, 1), Point::<i8>::DOWN_LEFT);
+        assert_eq!(Point::new(0u32, 0), Point::<u32>::ORIGIN);
+        assert_eq!(Point::<i16>::LEFT, Point::from_u8(b'<'));
+    }
+
+    #[test]
+    fn test_operators() {
+        let p: Point<i32> = Point::new(3, -4);
+
+        assert_eq!(
+            Point::new(4, -5),
+            p + Point::<i32>::DOWN_RIGHT - Point::new(0, 2)
+        );
+        assert_eq!(Point::new(9, -12), p * 3);
+        assert_eq!(
+            Point::new(1, 3),
+            (Point::new(7u8, 11) % 3) + Point::new(0, 1)
+        );
+        assert_eq!(7, p.manhattan(&Point::ORIGIN));
+        assert_eq!(4, p.chebyshev(&Point::ORIGIN));
+        assert_eq!(7, Point::new(2usize, 9).manhattan(&Point::new(6, 6)));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p: Point<i32> = Point::new(3, -4);
+
+        assert_eq!(Point::<i64>::new(3, -4), p.cast());
+        assert_eq!(Some(Point::<i8>::new(3, -4)), p.try_cast());
+        assert_eq!(None, p.try_cast::<u32>());
+        assert_eq!(None, Point::new(300i64, 0).try_cast::<u8>());
+
+        assert_eq!(p, (3, -4).into());
+        assert_eq!((3, -4), p.into());
+    }
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(4, 2, -1);
+
+        assert_eq!(Point3::new(5, 0, 2), a + b);
+        assert_eq!(Point3::new(-3, -4, 4), a - b);
+        assert_eq!(Point3::new(2, -4, 6), a * 2);
+        assert_eq!(11, a.manhattan(&b));
+        assert_eq!(4, a.chebyshev(&b));
+        assert_eq!(3, Point3::new(1u8, 2, 3).chebyshev(&Point3::ORIGIN));
+
+        assert_eq!(Point3::<i64>::new(1, -2, 3), a.cast());
+        assert_eq!(Some(Point3::<i8>::new(1, -2, 3)), a.try_cast());
+        assert_eq!(None, a.try_cast::<u32>());
+        assert_eq!(a, (1, -2, 3).into());
+        assert_eq!((1, -2, 3), a.into());
+    }
+
+    #[test]
+    fn test_neighbors_3d() {
+        let neighbors: FxHashSet<Point3> = Point3::NEIGHBORS.into_iter().collect();
+
+        assert_eq!(26, neighbors.len());
+        assert!(!neighbors.contains(&Point3::ORIGIN));
+        assert!(Point3::<i64>::CARDINALS
+            .iter()
+            .all(|p| neighbors.contains(p)));
+        assert!(Point3::<i64>::CARDINALS
+            .iter()
+            .all(|p| p.manhattan(&Point3::ORIGIN) == 1));
+        assert!(neighbors.iter().all(|p| p.chebyshev(&Point3::ORIGIN) == 1));
+
+        let expected: Vec<PointN<3>> = Point3::NEIGHBORS.into_iter().map(PointN::from).collect();
+        assert_eq!(expected, PointN::<3>::neighbors().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_point_n() {
+        let a = PointN::new([1, -2, 3, 0]);
+        let b = PointN::new([4, 2, -1, 2]);
+
+        assert_eq!(PointN::new([5, 0, 2, 2]), a + b);
+        assert_eq!(PointN::new([1, 2, -1, 2]), b % 3);
+        assert_eq!(13, a.manhattan(&b));
+        assert_eq!(4, a.chebyshev(&b));
+        assert_eq!(-2, a[1]);
+
+        assert_eq!(8, PointN::<4>::cardinals().count());
+        assert_eq!(80, PointN::<4>::neighbors().count());
+        assert_eq!(PointN::new([3, -4]), Point::new(3, -4).into());
+    }
+
+    #[test]
+    fn test_hash_map_keys() {
+        let mut cubes: FxHashMap<PointN<4>, usize> = FxHashMap::default();
+
+        for p in PointN::<4>::cardinals() {
+            *cubes.entry(p * 2).or_default() += 1;
+            *cubes.entry(p + p).or_default() += 1;
+        }
+
+        assert_eq!(8, cubes.len());
+        assert!(cubes.values().all(|&n| n == 2));
+    }
+}
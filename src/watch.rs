@@ -0,0 +1,319 @@
+// re-runs a day whenever one of its inputs or the source changes, printing how the answers changed
+// since the previous run
+//
+// the binary cannot reload its own code, so a changed source is rebuilt with cargo and the new
+// binary replaces the running one, handing over the previous answers in RUST_AOC_WATCH_PREVIOUS
+
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const PREVIOUS: &str = "RUST_AOC_WATCH_PREVIOUS";
+const INTERVAL: Duration = Duration::from_millis(500);
+
+// the answers of both parts, or why there are none
+pub type Outcome = Result<[String; 2], String>;
+
+pub struct Target<'a> {
+    pub name: String,
+    pub path: PathBuf,
+    pub solve: Box<dyn Fn() -> Outcome + 'a>,
+}
+
+// modification times of the files, directories are walked recursively and missing files left out
+fn snapshot(paths: &[&Path]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    let mut pending: Vec<PathBuf> = paths.iter().map(|p| p.to_path_buf()).collect();
+
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(&path).into_iter().flatten().flatten() {
+                pending.push(entry.path());
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            times.insert(path, modified);
+        }
+    }
+
+    times
+}
+
+fn diff(previous: Option<&Outcome>, current: &Outcome) -> Vec<String> {
+    match current {
+        Ok(answers) => (0..2)
+            .map(|i| {
+                let change = match previous {
+                    None => String::new(),
+                    Some(Ok(old)) if old[i] == answers[i] => " (unchanged)".to_string(),
+                    Some(Ok(old)) => format!(" (was {})", old[i]),
+                    Some(Err(_)) => " (was an error)".to_string(),
+                };
+
+                format!("part {}: {}{}", i + 1, answers[i], change)
+            })
+            .collect(),
+        Err(e) => {
+            let change = match previous {
+                Some(Ok(_)) => " (was solved)",
+                _ => "",
+            };
+
+            vec![format!("error: {}{}", e, change)]
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => res.push('\t'),
+            Some('n') => res.push('\n'),
+            Some(c) => res.push(c),
+            None => {}
+        }
+    }
+
+    res
+}
+
+// one line per target, "ok", "error" or nothing for a target that was not run yet
+fn encode(outcomes: &[Option<Outcome>]) -> String {
+    outcomes
+        .iter()
+        .map(|outcome| match outcome {
+            Some(Ok([one, two])) => format!("ok\t{}\t{}", escape(one), escape(two)),
+            Some(Err(e)) => format!("error\t{}", escape(e)),
+            None => String::new(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode(s: &str, targets: usize) -> Vec<Option<Outcome>> {
+    let outcomes: Vec<Option<Outcome>> = s
+        .split('\n')
+        .map(|line| {
+            let fields: Vec<String> = line.split('\t').map(unescape).collect();
+
+            match fields.as_slice() {
+                [status, one, two] if status == "ok" => Some(Ok([one.clone(), two.clone()])),
+                [status, e] if status == "error" => Some(Err(e.clone())),
+                _ => None,
+            }
+        })
+        .collect();
+
+    // answers of different targets cannot be compared
+    if outcomes.len() == targets {
+        outcomes
+    } else {
+        vec![None; targets]
+    }
+}
+
+// the crate the running binary was built from, wherever watch is started
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+// the source of the solvers, a change there rebuilds the binary
+pub fn sources() -> PathBuf {
+    manifest_dir().join("src")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Build {
+    Failed,
+    // cargo had nothing to do or built another binary than the running one
+    Unchanged,
+    Replaced,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// builds with the profile of the running binary, so that the build replaces it
+fn rebuild(exe: &Path) -> Build {
+    let before = modified(exe);
+
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    command
+        .args(["build", "--quiet", "--manifest-path"])
+        .arg(manifest_dir().join("Cargo.toml"));
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    if !command.status().is_ok_and(|status| status.success()) {
+        Build::Failed
+    } else if modified(exe) == before {
+        Build::Unchanged
+    } else {
+        Build::Replaced
+    }
+}
+
+// only returns if the new binary could not be started
+fn restart(exe: &Path, previous: &[Option<Outcome>]) -> io::Error {
+    let mut command = Command::new(exe);
+    command
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS, encode(previous));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        command.exec()
+    }
+
+    #[cfg(not(unix))]
+    match command.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(e) => e,
+    }
+}
+
+pub fn watch(targets: &[Target], sources: &Path) -> i32 {
+    // the path of the running binary no longer resolves once the build has replaced it
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            println!("Error: could not find the running binary: {}", e);
+            return 1;
+        }
+    };
+
+    let mut previous = match env::var(PREVIOUS) {
+        Ok(s) => decode(&s, targets.len()),
+        Err(_) => vec![None; targets.len()],
+    };
+
+    let paths: Vec<&Path> = targets.iter().map(|t| t.path.as_path()).collect();
+    let mut inputs = snapshot(&paths);
+    let mut code = snapshot(&[sources]);
+
+    loop {
+        for (target, previous) in targets.iter().zip(&mut previous) {
+            let outcome = (target.solve)();
+
+            println!("{} ({}):", target.name, target.path.display());
+
+            for line in diff(previous.as_ref(), &outcome) {
+                println!("  {}", line);
+            }
+
+            *previous = Some(outcome);
+        }
+
+        println!("\nWaiting for changes...");
+
+        loop {
+            thread::sleep(INTERVAL);
+
+            let current = snapshot(&[sources]);
+
+            if current != code {
+                code = current;
+                println!("\nSource changed, rebuilding");
+
+                match rebuild(&exe) {
+                    Build::Replaced => {
+                        let e = restart(&exe, &previous);
+                        println!("Error: could not restart: {}", e);
+                        return 1;
+                    }
+                    Build::Unchanged => {
+                        println!(
+                            "The build did not change {}, waiting for changes...",
+                            exe.display()
+                        )
+                    }
+                    Build::Failed => println!("Build failed, waiting for changes..."),
+                }
+            }
+
+            let current = snapshot(&paths);
+
+            if current != inputs {
+                inputs = current;
+                println!();
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(one: &str, two: &str) -> Outcome {
+        Ok([one.to_string(), two.to_string()])
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            vec!["part 1: 7036", "part 2: 45"],
+            diff(None, &solved("7036", "45"))
+        );
+        assert_eq!(
+            vec!["part 1: 7036 (unchanged)", "part 2: 45 (was 44)"],
+            diff(Some(&solved("7036", "44")), &solved("7036", "45"))
+        );
+        assert_eq!(
+            vec!["error: no start (was solved)"],
+            diff(Some(&solved("7036", "45")), &Err("no start".to_string()))
+        );
+        assert_eq!(
+            vec!["part 1: 1 (was an error)", "part 2: 2 (was an error)"],
+            diff(Some(&Err("no start".to_string())), &solved("1", "2"))
+        );
+    }
+
+    // the sources are found from any working directory
+    #[test]
+    fn test_sources() {
+        assert!(sources().is_absolute());
+        assert!(sources().join("watch.rs").exists());
+        assert_eq!(None, modified(&sources().join("missing.rs")));
+    }
+
+    #[test]
+    fn test_encode() {
+        let outcomes = vec![
+            Some(solved("#..\n.#\\", "a\tb")),
+            Some(Err("line 1, column 2: unexpected character".to_string())),
+            None,
+        ];
+
+        assert_eq!(outcomes, decode(&encode(&outcomes), 3));
+        assert_eq!(vec![None, None], decode(&encode(&outcomes), 2));
+    }
+}
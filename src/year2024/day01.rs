@@ -1,19 +1,22 @@
 use rustc_hash::FxHashMap;
 use std::iter::zip;
 
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{parse_at, split_once_at},
+};
+
 type Input = (Vec<u32>, Vec<u32>);
 
-pub fn parse(input: &str) -> Input {
-    let (left, right) = input
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
         .lines()
         .map(|line| {
-            line.split_once("   ")
-                .map(|(a, b)| (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap()))
-                .unwrap()
-        })
-        .unzip();
+            let (a, b) = split_once_at(input, line, "   ")?;
 
-    (left, right)
+            Ok((parse_at::<u32>(input, a)?, parse_at::<u32>(input, b)?))
+        })
+        .collect()
 }
 
 pub fn solve_part_one(input: &Input) -> u32 {
@@ -39,41 +42,25 @@ pub fn solve_part_two(input: &Input) -> u32 {
         .sum()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day01.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(11, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(31, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
@@ -1,18 +1,19 @@
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::parse_at,
+};
+
 pub struct Input {
     lists: Vec<Vec<i32>>,
 }
 
-pub fn parse(input: &str) -> Input {
+pub fn parse(input: &str) -> Result<Input, ParseError> {
     let lists = input
         .lines()
-        .map(|line| {
-            line.split(' ')
-                .map(|n| n.parse().expect("could not parse the input"))
-                .collect()
-        })
-        .collect();
+        .map(|line| line.split(' ').map(|n| parse_at(input, n)).collect())
+        .collect::<Result<_, _>>()?;
 
-    Input { lists }
+    Ok(Input { lists })
 }
 
 fn is_safe(list: &[i32]) -> bool {
@@ -42,41 +43,25 @@ pub fn solve_part_two(input: &Input) -> usize {
         .count()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day02.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(2, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(4, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
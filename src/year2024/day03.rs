@@ -1,10 +1,12 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::solution::{Answer, ParseError, Solution};
+
 type Input<'a> = &'a str;
 
-pub fn parse(input: &str) -> Input {
-    input
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    Ok(input)
 }
 
 pub fn solve_part_one(input: &Input) -> usize {
@@ -28,42 +30,25 @@ pub fn solve_part_two(input: &Input) -> usize {
         .sum()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+pub struct Puzzle;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
 
-    static TEST_DATA_PATH_1: &str = "data/test/year2024/day03-1.txt";
-    static TEST_DATA_PATH_2: &str = "data/test/year2024/day03-2.txt";
+    type Input<'a> = &'a str;
+    type Params = ();
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH_1).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(161, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH_2).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(48, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
@@ -1,3 +1,10 @@
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::point::Point,
+};
+
+type Index = Point<i32>;
+
 pub struct Grid {
     chars: Vec<char>,
     size: Index,
@@ -17,19 +24,10 @@ impl Grid {
     }
 
     fn to_index(&self, i: i32) -> Index {
-        Index {
-            x: i % self.size.x,
-            y: i / self.size.x,
-        }
+        Point::new(i % self.size.x, i / self.size.x)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Index {
-    x: i32,
-    y: i32,
-}
-
 enum Direction {
     N,
     NE,
@@ -53,39 +51,41 @@ static DIRECTIONS: [Direction; 8] = [
 ];
 
 impl Direction {
-    fn to_tuple(&self) -> (i32, i32) {
+    fn to_point(&self) -> Index {
         match self {
-            Direction::N => (0, -1),
-            Direction::NE => (1, -1),
-            Direction::E => (1, 0),
-            Direction::SE => (1, 1),
-            Direction::S => (0, 1),
-            Direction::SW => (-1, 1),
-            Direction::W => (-1, 0),
-            Direction::NW => (-1, -1),
+            Direction::N => Index::UP,
+            Direction::NE => Index::UP_RIGHT,
+            Direction::E => Index::RIGHT,
+            Direction::SE => Index::DOWN_RIGHT,
+            Direction::S => Index::DOWN,
+            Direction::SW => Index::DOWN_LEFT,
+            Direction::W => Index::LEFT,
+            Direction::NW => Index::UP_LEFT,
         }
     }
 }
 
-pub fn parse(input: &str) -> Grid {
-    let y = input.lines().count() as i32;
-    let x = input.split_once('\n').unwrap().0.len() as i32;
-    let chars: Vec<char> = input.lines().flat_map(|line| line.chars()).collect();
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    // the byte grid checks that every row has the same width
+    let bytes = crate::util::grid::Grid::parse(input)?;
+    let chars: Vec<char> = bytes.elements.iter().map(|b| *b as char).collect();
 
-    Grid {
+    Ok(Grid {
         chars,
-        size: Index { x, y },
-    }
+        size: bytes
+            .size
+            .try_cast()
+            .ok_or_else(|| ParseError::at(input, input, "the grid is too large"))?,
+    })
 }
 
 fn take_n_steps(grid: &Grid, index: &Index, d: &Direction, steps: i32) -> Option<Index> {
-    let (i, j) = d.to_tuple();
-    let (x, y) = (index.x + steps * i, index.y + steps * j);
+    let p = *index + d.to_point() * steps;
 
-    if x < 0 || x >= grid.size.x || y < 0 || y >= grid.size.y {
+    if p.x < 0 || p.x >= grid.size.x || p.y < 0 || p.y >= grid.size.y {
         None
     } else {
-        Some(Index { x, y })
+        Some(p)
     }
 }
 
@@ -154,41 +154,25 @@ pub fn solve_part_two(grid: &Grid) -> usize {
         .sum()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+    type Input<'a> = Grid;
+    type Params = ();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day04.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(18, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(9, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
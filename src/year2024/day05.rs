@@ -1,5 +1,10 @@
 use std::{cmp, collections::HashMap};
 
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{parse_at, split_once_at},
+};
+
 type Ordering = HashMap<(u32, u32), cmp::Ordering>;
 
 #[derive(Debug, Clone)]
@@ -8,30 +13,32 @@ pub struct Input {
     ordering: Ordering,
 }
 
-pub fn parse(input: &str) -> Input {
-    let (rules, pages) = input.split_once("\n\n").unwrap();
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (rules, pages) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end(input, "expected the updates after an empty line"))?;
 
     let mut ordering = HashMap::new();
 
-    rules.lines().for_each(|s| {
-        let (a, b) = s.split_once('|').unwrap();
-        let a = a.parse().unwrap();
-        let b = b.parse().unwrap();
+    for s in rules.lines() {
+        let (a, b) = split_once_at(input, s, "|")?;
+        let a = parse_at(input, a)?;
+        let b = parse_at(input, b)?;
 
         if a < b {
             ordering.insert((a, b), cmp::Ordering::Less);
         } else {
             ordering.insert((b, a), cmp::Ordering::Greater);
         }
-    });
+    }
 
-    Input {
+    Ok(Input {
         pages: pages
             .lines()
-            .map(|s| s.split(',').map(|a| a.parse().unwrap()).collect())
-            .collect(),
+            .map(|s| s.split(',').map(|a| parse_at(input, a)).collect())
+            .collect::<Result<_, _>>()?,
         ordering,
-    }
+    })
 }
 
 fn middle<T>(list: &[T]) -> &T {
@@ -77,41 +84,25 @@ pub fn solve_part_two(input: &Input) -> usize {
         .sum::<u32>() as usize
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
+pub struct Puzzle;
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    type Input<'a> = Input;
+    type Params = ();
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day05.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(143, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(123, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
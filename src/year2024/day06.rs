@@ -1,10 +1,17 @@
-use std::{collections::HashSet, hash::Hash, str::FromStr};
-
-use crate::util::{
-    grid::Grid,
-    point::{Point, DOWN, LEFT, RIGHT, UP},
+use std::{collections::HashSet, fmt, hash::Hash, str::FromStr};
+
+use crate::{
+    aoc_trace,
+    solution::{Answer, ParseError, Solution},
+    util::{
+        grid::Grid,
+        parse::check_chars,
+        point::{Point, DOWN, LEFT, RIGHT, UP},
+    },
 };
 
+type Position = Point<i32>;
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum Guard {
     Up,
@@ -14,7 +21,8 @@ enum Guard {
 }
 
 impl Guard {
-    fn to_u8(&self) -> u8 {
+    #[allow(dead_code)]
+    fn to_u8(self) -> u8 {
         match self {
             Guard::Up => 0b1000,
             Guard::Right => 0b0100,
@@ -23,12 +31,12 @@ impl Guard {
         }
     }
 
-    fn to_tuple(&self) -> (i32, i32) {
+    fn to_point(self) -> Position {
         match self {
-            Guard::Up => (0, -1),
-            Guard::Down => (0, 1),
-            Guard::Left => (-1, 0),
-            Guard::Right => (1, 0),
+            Guard::Up => Position::UP,
+            Guard::Down => Position::DOWN,
+            Guard::Left => Position::LEFT,
+            Guard::Right => Position::RIGHT,
         }
     }
 
@@ -62,11 +70,11 @@ enum CellState {
     Blocked,
 }
 
-impl ToString for CellState {
-    fn to_string(&self) -> String {
+impl fmt::Display for CellState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            CellState::Empty => ".".to_string(),
-            CellState::Blocked => "#".to_string(),
+            CellState::Empty => write!(f, "."),
+            CellState::Blocked => write!(f, "#"),
         }
     }
 }
@@ -74,25 +82,22 @@ impl ToString for CellState {
 #[derive(Debug, Clone)]
 pub struct Map {
     grid: Vec<CellState>,
-    grid_size: (i32, i32),
-    starting_position: (i32, i32),
-    guard_position: (i32, i32),
+    grid_size: Position,
+    starting_position: Position,
+    guard_position: Position,
     guard_direction: Guard,
     can_block: usize,
-    seen: HashSet<(i32, i32)>,
-    visited: HashSet<((i32, i32), Guard)>,
-    lookahead: HashSet<((i32, i32), Guard)>,
+    seen: HashSet<Position>,
+    visited: HashSet<(Position, Guard)>,
+    lookahead: HashSet<(Position, Guard)>,
     // might want to take care of loops here
 }
 
 impl Map {
     fn move_guard(&mut self) -> Result<(), String> {
-        let (x, y) = self.guard_direction.to_tuple();
-        let (guard_x, guard_y) = self.guard_position;
-
-        let (new_x, new_y) = (guard_x + x, guard_y + y);
+        let new = self.guard_position + self.guard_direction.to_point();
 
-        if !valid_position(&(new_x, new_y), &self.grid_size) {
+        if !valid_position(&new, &self.grid_size) {
             // can't block because box would go outside grid
 
             self.visited
@@ -101,7 +106,7 @@ impl Map {
             return Err("Guard is out of bounds".to_string());
         }
 
-        let i = (new_y * self.grid_size.0 + new_x) as usize;
+        let i = (new.y * self.grid_size.x + new.x) as usize;
 
         match self.grid[i] {
             CellState::Empty => {
@@ -113,8 +118,7 @@ impl Map {
                 self.seen.insert(self.guard_position);
 
                 // then try rotating and see what happens
-                if self.starting_position != (new_x, new_y) && !self.seen.contains(&(new_x, new_y))
-                {
+                if self.starting_position != new && !self.seen.contains(&new) {
                     self.grid[i] = CellState::Blocked;
 
                     self.can_block +=
@@ -124,7 +128,7 @@ impl Map {
                 }
 
                 // then move into it
-                self.guard_position = (new_x, new_y);
+                self.guard_position = new;
             }
             CellState::Blocked => {
                 self.guard_direction = self.guard_direction.rotate();
@@ -135,21 +139,22 @@ impl Map {
         Ok(())
     }
 
-    fn print_grid(&self) {
-        for i in 0..self.grid_size.1 {
-            for j in 0..self.grid_size.0 {
-                print!(
-                    "{:4},",
-                    self.grid[(i * self.grid_size.0 + j) as usize].to_string()
-                );
+    fn render_grid(&self) -> String {
+        let mut res = String::new();
+
+        for row in self.grid.chunks(self.grid_size.x as usize) {
+            for cell in row {
+                res += &format!("{:4},", cell.to_string());
             }
+            res.push('\n');
         }
+
+        res
     }
 
-    fn find_loop(&mut self, mut pos: (i32, i32), mut dir: Guard) -> usize {
+    fn find_loop(&mut self, mut pos: Position, mut dir: Guard) -> usize {
         loop {
-            let (x, y) = dir.to_tuple();
-            let new_pos = (pos.0 + x, pos.1 + y);
+            let new_pos = pos + dir.to_point();
 
             // check if new position is valid, if not, we left the grid and so no loop happens
             if !valid_position(&new_pos, &self.grid_size) {
@@ -157,7 +162,7 @@ impl Map {
                 return 0;
             }
 
-            let i = new_pos.1 * self.grid_size.0 + new_pos.0;
+            let i = new_pos.y * self.grid_size.x + new_pos.x;
 
             match self.grid[i as usize] {
                 CellState::Empty => {
@@ -179,39 +184,30 @@ impl Map {
     }
 }
 
-fn valid_position(pos: &(i32, i32), size: &(i32, i32)) -> bool {
-    pos.0 >= 0 && pos.1 >= 0 && pos.0 < size.0 && pos.1 < size.1
+fn valid_position(pos: &Position, size: &Position) -> bool {
+    pos.x >= 0 && pos.y >= 0 && pos.x < size.x && pos.y < size.y
 }
 
-pub fn parse(input: &str) -> Map {
-    let grid_size = (
-        input.lines().next().unwrap().len() as i32,
-        input.lines().count() as i32,
-    );
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    check_chars(input, input, ".#^")?;
 
-    let grid: Vec<CellState> = input
-        .as_bytes()
+    let Input { map, start } = parse_(input)?;
+
+    let grid_size = Position::new(map.size.x as i32, map.size.y as i32);
+
+    let grid: Vec<CellState> = map
+        .elements
         .iter()
-        .filter(|c| **c != b'\n')
         .map(|c| match c {
-            b'.' => CellState::Empty,
-            b'^' => CellState::Empty,
             b'#' => CellState::Blocked,
-            _ => panic!("unexpected char"),
+            _ => CellState::Empty,
         })
         .collect();
 
-    let i = input
-        .as_bytes()
-        .iter()
-        .filter(|c| **c != b'\n')
-        .position(|c| *c == b'^')
-        .unwrap() as i32;
-
-    let guard_position = (i % grid_size.0, i / grid_size.0);
+    let guard_position = Position::new(start.x as i32, start.y as i32);
     let starting_position = guard_position;
 
-    Map {
+    Ok(Map {
         visited: HashSet::with_capacity(grid.len() * 4),
         lookahead: HashSet::with_capacity(grid.len() * 4),
         seen: HashSet::with_capacity(grid.len()),
@@ -221,7 +217,7 @@ pub fn parse(input: &str) -> Map {
         guard_position,
         guard_direction: Guard::Up,
         can_block: 0,
-    }
+    })
 }
 
 pub fn solve_part_one(input: &Map) -> usize {
@@ -243,11 +239,13 @@ pub struct Input {
     start: Point,
 }
 
-pub fn parse_(input: &str) -> Input {
-    let map = Grid::from_str(input);
-    let start = map.find(|c| *c == b'^').expect("map must have a guard");
+pub fn parse_(input: &str) -> Result<Input, ParseError> {
+    let map = Grid::parse(input)?;
+    let start = map
+        .find(|c| *c == b'^')
+        .ok_or_else(|| ParseError::end(input, "expected a guard '^' on the map"))?;
 
-    Input { map, start }
+    Ok(Input { map, start })
 }
 
 fn rotate_clockwise(direction: &Point) -> Point {
@@ -286,7 +284,7 @@ pub fn solve_part_one_(input: &Input) -> usize {
     res
 }
 
-pub fn solve_part_two_(input: &Input) -> usize {
+pub fn solve_part_two_(_input: &Input) -> usize {
     0
 }
 
@@ -295,48 +293,30 @@ pub fn solve_part_two(input: &Map) -> usize {
 
     while map.move_guard().is_ok() {}
 
-    // map.print_grid();
+    aoc_trace!("grid:\n{}", map.render_grid());
 
     map.can_block
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let uwu = parse_(input);
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one_(&uwu).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+pub struct Puzzle;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day06.txt";
+    type Input<'a> = Map;
+    type Params = ();
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(41, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(6, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
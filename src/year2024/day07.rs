@@ -1,5 +1,10 @@
 use std::mem::swap;
 
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{parse_at, split_once_at},
+};
+
 #[derive(Debug)]
 pub struct Equation {
     result: i64,
@@ -19,16 +24,19 @@ fn next_power_of_ten(n: i64) -> i64 {
     }
 }
 
-pub fn parse(input: &str) -> Input {
+pub fn parse(input: &str) -> Result<Input, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (result, operands) = line.split_once(": ").unwrap();
-
-            Equation {
-                result: result.parse::<i64>().unwrap(),
-                operands: operands.split(' ').map(|n| n.parse().unwrap()).collect(),
-            }
+            let (result, operands) = split_once_at(input, line, ": ")?;
+
+            Ok(Equation {
+                result: parse_at(input, result)?,
+                operands: operands
+                    .split(' ')
+                    .map(|n| parse_at(input, n))
+                    .collect::<Result<_, _>>()?,
+            })
         })
         .collect()
 }
@@ -61,6 +69,7 @@ fn upper_bound(eq: &Equation) -> i64 {
     i
 }
 
+#[allow(dead_code)]
 fn upper_bound_concat(eq: &Equation) -> i64 {
     let mut i = *eq.operands.first().unwrap();
 
@@ -134,7 +143,7 @@ pub fn solve_part_one(input: &Input) -> usize {
             continue;
         }
 
-        if is_possible(&eq) {
+        if is_possible(eq) {
             res += eq.result;
         }
     }
@@ -154,7 +163,7 @@ pub fn solve_part_two(input: &Input) -> usize {
         //     continue;
         // }
 
-        if is_possible_2(&eq) {
+        if is_possible_2(eq) {
             res += eq.result;
         }
     }
@@ -162,41 +171,25 @@ pub fn solve_part_two(input: &Input) -> usize {
     res as usize
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/input/year2024/day07.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(3749, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(11387, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
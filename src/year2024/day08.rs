@@ -1,9 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::util::grid::Grid;
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::grid::Grid,
+};
 
-pub fn parse(input: &str) -> Grid<u8> {
-    Grid::from_str(input)
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
 }
 
 pub fn solve_part_one(input: &Grid<u8>) -> usize {
@@ -78,13 +81,13 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
 
                 while input.in_grid(&antinode) {
                     antinodes.insert(antinode);
-                    antinode = antinode + diff;
+                    antinode += diff;
                 }
 
                 let mut antinode = *q;
                 while input.in_grid(&antinode) {
                     antinodes.insert(antinode);
-                    antinode = antinode - diff;
+                    antinode -= diff;
                 }
             }
         }
@@ -93,41 +96,25 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
     antinodes.len()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day08.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = Grid<u8>;
+    type Params = ();
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(14, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(34, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
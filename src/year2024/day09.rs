@@ -1,16 +1,25 @@
+use crate::solution::{Answer, ParseError, Solution};
+
 type Input = Vec<Option<u32>>;
 
-pub fn parse(input: &str) -> Input {
-    let len: usize = input.chars().fold(0, |sum, x| {
-        sum + (x.to_digit(10).unwrap_or_default() as usize)
-    });
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let disk_map = input.trim_end();
+    let mut digits = Vec::with_capacity(disk_map.len());
+
+    for (i, c) in disk_map.char_indices() {
+        let digit = c.to_digit(10).ok_or_else(|| {
+            ParseError::at(input, &disk_map[i..i + c.len_utf8()], "expected a digit")
+        })?;
+
+        digits.push(digit);
+    }
+
+    let len: usize = digits.iter().map(|d| *d as usize).sum();
 
     let mut expanded = Vec::with_capacity(len);
     let mut block_id = 0;
 
-    for (i, c) in input.chars().enumerate() {
-        let c = c.to_digit(10u32).unwrap_or_default();
-
+    for (i, c) in digits.into_iter().enumerate() {
         for _ in 0..c {
             if i % 2 == 0 {
                 expanded.push(Some(block_id));
@@ -23,7 +32,7 @@ pub fn parse(input: &str) -> Input {
         }
     }
 
-    expanded
+    Ok(expanded)
 }
 
 pub fn solve_part_one(input: &Input) -> usize {
@@ -36,12 +45,12 @@ pub fn solve_part_one(input: &Input) -> usize {
     // defragment
     loop {
         // advance to next free spot
-        while let Some(_) = expanded[head] {
+        while expanded[head].is_some() {
             head += 1;
         }
 
         // find first filled spot
-        while let None = expanded[tail] {
+        while expanded[tail].is_none() {
             tail -= 1;
         }
 
@@ -71,14 +80,12 @@ pub fn solve_part_two(input: &Input) -> usize {
 
     // defragment
     let mut head: usize = 0;
-    let mut gap_size = 0;
-    let mut file_size = 0;
     let mut tail = expanded.len() - 1;
 
     // defragment
     loop {
         // find first filled spot
-        while let None = expanded[tail] {
+        while expanded[tail].is_none() {
             tail -= 1;
         }
 
@@ -90,7 +97,7 @@ pub fn solve_part_two(input: &Input) -> usize {
             break;
         }
 
-        file_size = 0;
+        let mut file_size = 0;
         while let Some(i) = expanded[tail - file_size] {
             if i != file_type {
                 break;
@@ -100,7 +107,7 @@ pub fn solve_part_two(input: &Input) -> usize {
 
         // look for a gap
         loop {
-            while let Some(_) = expanded[head] {
+            while expanded[head].is_some() {
                 head += 1;
             }
 
@@ -108,8 +115,8 @@ pub fn solve_part_two(input: &Input) -> usize {
                 break;
             }
 
-            gap_size = 0;
-            while let None = expanded[head + gap_size] {
+            let mut gap_size = 0;
+            while expanded[head + gap_size].is_none() {
                 gap_size += 1;
             }
 
@@ -136,50 +143,33 @@ pub fn solve_part_two(input: &Input) -> usize {
     let mut checksum = 0;
 
     for e in expanded.iter().enumerate() {
-        match e {
-            (i, Some(x)) => checksum += i * (*x as usize),
-            (_, None) => (),
+        if let (i, Some(x)) = e {
+            checksum += i * (*x as usize)
         }
     }
 
     checksum
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day09.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = Input;
+    type Params = ();
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(1928, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(2858, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
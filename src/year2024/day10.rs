@@ -1,12 +1,15 @@
 use std::{collections::HashSet, mem::swap};
 
-use crate::util::{
-    grid::Grid,
-    point::{Point, CARDINALS},
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::{
+        grid::Grid,
+        point::{Point, CARDINALS},
+    },
 };
 
-pub fn parse(input: &str) -> Grid<u8> {
-    Grid::from_str(input)
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
 }
 
 pub fn score(grid: &Grid<u8>, p: Point) -> usize {
@@ -16,12 +19,12 @@ pub fn score(grid: &Grid<u8>, p: Point) -> usize {
 
     while level < b'9' {
         for p in &curr {
-            CARDINALS.map(|dir| {
+            for dir in CARDINALS {
                 let q = *p + dir;
                 if grid.in_grid(&q) && grid[q] == level + 1 {
                     next.insert(q);
                 }
-            });
+            }
         }
         //println!("{:?}", next);
         curr.clear();
@@ -34,25 +37,23 @@ pub fn score(grid: &Grid<u8>, p: Point) -> usize {
 
 pub fn rate(grid: &Grid<u8>, p: Point) -> usize {
     // find all paths from p to reachable 9s
-    let mut level = grid[p];
+    let level = grid[p];
     let mut res = 0;
 
     let mut stack = vec![(p, level)];
 
-    while !stack.is_empty() {
-        let (q, l) = stack.pop().unwrap();
-
+    while let Some((q, l)) = stack.pop() {
         if l == b'9' {
             res += 1;
             continue;
         }
 
-        CARDINALS.map(|dir| {
+        for dir in CARDINALS {
             let r = q + dir;
             if grid.in_grid(&r) && grid[r] == l + 1 {
                 stack.push((r, l + 1));
             }
-        });
+        }
     }
 
     res
@@ -64,7 +65,7 @@ pub fn solve_part_one(grid: &Grid<u8>) -> usize {
     let starting_points = grid.find_all(|c| *c == b'0');
 
     for p in starting_points {
-        let s = score(&grid, p);
+        let s = score(grid, p);
         res += s;
     }
 
@@ -77,48 +78,32 @@ pub fn solve_part_two(grid: &Grid<u8>) -> usize {
     let starting_points = grid.find_all(|c| *c == b'0');
 
     for p in starting_points {
-        let s = rate(&grid, p);
+        let s = rate(grid, p);
         res += s;
     }
 
     res
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
+pub struct Puzzle;
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Hoof It";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    type Input<'a> = Grid<u8>;
+    type Params = ();
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day10.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(36, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(81, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
@@ -1,9 +1,44 @@
 use std::collections::HashMap;
 
+use crate::{
+    solution::{parameter, unknown_parameter, Answer, Parameters, ParseError, Solution},
+    util::parse::parse_at,
+};
+
 type Input = Vec<u64>;
 
-pub fn parse(input: &str) -> Input {
-    input.split(' ').map(|c| c.parse().unwrap()).collect()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub part_one_blinks: usize,
+    pub part_two_blinks: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part_one_blinks: 25,
+            part_two_blinks: 75,
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "part_one_blinks" => self.part_one_blinks = parameter(name, value)?,
+            "part_two_blinks" => self.part_two_blinks = parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .split_whitespace()
+        .map(|c| parse_at(input, c))
+        .collect()
 }
 
 #[inline]
@@ -55,7 +90,7 @@ fn blink_memoized(stones: &Vec<u64>, blinks: usize) -> usize {
             } else {
                 let digits = digits(stone);
 
-                if digits % 2 == 0 {
+                if digits.is_multiple_of(2) {
                     let (a, b) = split(stone, digits);
 
                     let mut both = true;
@@ -104,9 +139,8 @@ fn blink_memoized(stones: &Vec<u64>, blinks: usize) -> usize {
     res as usize
 }
 
+#[allow(dead_code)]
 fn blink_faster(stone: u64, blinks: usize) -> usize {
-    let res = 0;
-
     let mut stones = vec![0; blinks + 1];
     let mut positions = Vec::with_capacity(blinks + 1);
 
@@ -125,7 +159,7 @@ fn blink_faster(stone: u64, blinks: usize) -> usize {
             } else {
                 let d = digits(stone);
 
-                if d % 2 == 0 {
+                if d.is_multiple_of(2) {
                     let (a, b) = split(stone, d);
 
                     stone = a;
@@ -147,6 +181,7 @@ fn blink_faster(stone: u64, blinks: usize) -> usize {
     res
 }
 
+#[allow(dead_code)]
 fn blink(stones: &mut Vec<u64>) {
     let mut temp = Vec::with_capacity(stones.len());
 
@@ -156,9 +191,8 @@ fn blink(stones: &mut Vec<u64>) {
         } else {
             let d = digits(*stone);
 
-            if d % 2 == 0 {
+            if d.is_multiple_of(2) {
                 let (a, b) = split(*stone, d);
-                let s = stone.to_string();
 
                 *stone = a;
                 temp.push(b);
@@ -171,49 +205,33 @@ fn blink(stones: &mut Vec<u64>) {
     stones.extend_from_slice(&temp);
 }
 
-pub fn solve_part_one(input: &Input) -> usize {
-    blink_memoized(&input, 25)
-}
-
-pub fn solve_part_two(input: &Input) -> usize {
-    blink_memoized(&input, 75)
+pub fn solve_part_one(input: &Input, params: &Params) -> usize {
+    blink_memoized(input, params.part_one_blinks)
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
+pub fn solve_part_two(input: &Input, params: &Params) -> usize {
+    blink_memoized(input, params.part_two_blinks)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day11.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = Params;
 
-        assert_eq!(55312, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    fn part_one(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_one(input, params).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_two(input, params).into()
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    solution::{Answer, ParseError, Solution},
     util::grid::Grid,
     util::point::{Point, CARDINALS, NEIGHBORS},
 };
@@ -27,9 +28,7 @@ fn score_regions(grid: &Grid<u8>) -> usize {
             let mut perimeter = 0;
 
             // start search from this point
-            while !stack.is_empty() {
-                let p = stack.pop().unwrap();
-
+            while let Some(p) = stack.pop() {
                 if visited[p] {
                     continue;
                 } else {
@@ -143,8 +142,8 @@ fn score_regions_(grid: &Grid<u8>) -> usize {
     total
 }
 
-pub fn parse(input: &str) -> Grid<u8> {
-    Grid::from_str(input)
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
 }
 
 pub fn solve_part_one(input: &Grid<u8>) -> usize {
@@ -155,41 +154,25 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
     score_regions_(input)
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day12.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Grid<u8>;
+    type Params = ();
 
-        assert_eq!(1930, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(1206, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
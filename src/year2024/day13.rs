@@ -1,6 +1,9 @@
 use regex::Regex;
 
-use crate::util::point::Point;
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::{parse::parse_at, point::Point},
+};
 
 #[derive(Debug)]
 pub struct Equation {
@@ -9,31 +12,40 @@ pub struct Equation {
     z: Point,
 }
 
-fn parse_point(desc: &str, re: &Regex) -> Point {
-    let caps = re.captures(desc).expect("Invalid point description");
+fn parse_point(input: &str, desc: &str, re: &Regex) -> Result<Point, ParseError> {
+    let caps = re
+        .captures(desc)
+        .ok_or_else(|| ParseError::at(input, desc, "invalid point description"))?;
 
-    Point::new(caps[1].parse().unwrap(), caps[2].parse().unwrap())
+    let coordinate = |i| parse_at(input, caps.get(i).map_or("", |m| m.as_str()));
+
+    Ok(Point::new(coordinate(1)?, coordinate(2)?))
 }
 
 type Input = Vec<Equation>;
 
-pub fn parse(input: &str) -> Input {
-    let mut it = input.lines();
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let lines: Vec<_> = input.lines().collect();
     let mut res = Vec::new();
 
     let re = Regex::new(r".*X[+=](\d+), Y[+=](\d+)").unwrap();
 
-    while let (Some(v1), Some(v2), Some(v3), _) = (it.next(), it.next(), it.next(), it.next()) {
+    // every machine is described by three lines and followed by an empty one
+    for machine in lines.chunks(4) {
+        let [v1, v2, v3, ..] = machine else {
+            return Err(ParseError::end(input, "expected three lines per machine"));
+        };
+
         let (a, b, z) = (
-            parse_point(v1, &re),
-            parse_point(v2, &re),
-            parse_point(v3, &re),
+            parse_point(input, v1, &re)?,
+            parse_point(input, v2, &re)?,
+            parse_point(input, v3, &re)?,
         );
 
         res.push(Equation { a, b, z });
     }
 
-    res
+    Ok(res)
 }
 
 fn solve_equation(eq: &Equation) -> Option<(i64, i64)> {
@@ -61,7 +73,7 @@ fn solve_equation(eq: &Equation) -> Option<(i64, i64)> {
 pub fn solve_part_one(input: &Input) -> usize {
     input
         .iter()
-        .filter_map(|eq| solve_equation(eq))
+        .filter_map(solve_equation)
         .map(|(x, y)| 3 * x + y)
         .sum::<i64>() as usize
 }
@@ -82,41 +94,25 @@ pub fn solve_part_two(input: &Input) -> usize {
         .sum::<i64>() as usize
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+pub struct Puzzle;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day13.txt";
+    type Input<'a> = Input;
+    type Params = ();
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(480, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(875318608908, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
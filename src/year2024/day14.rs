@@ -1,6 +1,10 @@
 use regex::Regex;
 
-use crate::util::{grid::Grid, point::Point};
+use crate::{
+    aoc_trace,
+    solution::{parameter_in, unknown_parameter, Answer, Parameters, ParseError, Solution},
+    util::{grid::Grid, parse::parse_at, point::Point},
+};
 
 #[derive(Debug)]
 pub struct Robot {
@@ -10,22 +14,55 @@ pub struct Robot {
 
 type Input = Vec<Robot>;
 
-pub fn parse(input: &str) -> Vec<Robot> {
-    let re = Regex::new(r"p=(\d+),(\d+) v=(\-*\d+),(\-*\d+)").unwrap();
-    let mut res = Vec::new();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub width: i64,
+    pub height: i64,
+    // part one counts the robots after this many seconds
+    pub seconds: i64,
+}
 
-    input.lines().for_each(|line| {
-        let caps = re
-            .captures(line)
-            .expect("Expected valid robot specification");
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
 
-        res.push(Robot {
-            p: Point::new(caps[1].parse().unwrap(), caps[2].parse().unwrap()),
-            v: Point::new(caps[3].parse().unwrap(), caps[4].parse().unwrap()),
-        })
-    });
+impl Parameters for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "width" => self.width = parameter_in(name, value, 1..)?,
+            "height" => self.height = parameter_in(name, value, 1..)?,
+            "seconds" => self.seconds = parameter_in(name, value, 0..)?,
+            _ => return Err(unknown_parameter(name)),
+        }
 
-    res
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    let re = Regex::new(r"p=(\d+),(\d+) v=(\-*\d+),(\-*\d+)").unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::at(input, line, "invalid robot specification"))?;
+
+            let n = |i| parse_at(input, caps.get(i).map_or("", |m| m.as_str()));
+
+            Ok(Robot {
+                p: Point::new(n(1)?, n(2)?),
+                v: Point::new(n(3)?, n(4)?),
+            })
+        })
+        .collect()
 }
 
 fn move_robot(robot: &Robot, room_size: &Point, seconds: i64) -> Point {
@@ -73,13 +110,13 @@ fn quadrant(pos: Point, room_size: &Point) -> Option<usize> {
     None
 }
 
-pub fn solve_part_one(input: &Input) -> usize {
-    let seconds = 100;
-    let room_size = Point::new(101, 103);
+pub fn solve_part_one(input: &Input, params: &Params) -> usize {
+    let seconds = params.seconds;
+    let room_size = Point::new(params.width, params.height);
 
     // println!("{:?}", robots);
 
-    let mut quadrant_counts = vec![0; 4];
+    let mut quadrant_counts = [0; 4];
 
     input
         .iter()
@@ -93,23 +130,20 @@ pub fn solve_part_one(input: &Input) -> usize {
     quadrant_counts.iter().product()
 }
 
-fn print_robots(robots: &Vec<Point>) {
-    let mut grid = Grid::new_with_element(101, 103, '.');
+fn render_robots(robots: &[Point], room_size: &Point) -> String {
+    let mut grid = Grid::new_with_element(room_size.x, room_size.y, b'.');
 
     for robot in robots {
-        grid[*robot] = '#';
+        grid[*robot] = b'#';
     }
 
-    grid.print();
+    grid.render_chars()
 }
 
 fn scan_clusters(points: Vec<Point>, room_size: &Point) -> bool {
     let mut x_counts = vec![0; room_size.x as usize];
     let mut y_counts = vec![0; room_size.y as usize];
 
-    let c = 20;
-    let d = 15;
-
     for p in points {
         x_counts[p.x as usize] += 1;
         y_counts[p.y as usize] += 1;
@@ -124,9 +158,9 @@ fn scan_clusters(points: Vec<Point>, room_size: &Point) -> bool {
     false
 }
 
-pub fn solve_part_two(input: &Input) -> usize {
+pub fn solve_part_two(input: &Input, params: &Params) -> usize {
     let mut i = 0;
-    let room_size = Point::new(101, 103);
+    let room_size = Point::new(params.width, params.height);
 
     while !scan_clusters(
         input
@@ -138,44 +172,36 @@ pub fn solve_part_two(input: &Input) -> usize {
         i += 1;
     }
 
-    i as usize
-}
-
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
+    aoc_trace!("after {} seconds:\n{}", i, {
+        let robots: Vec<Point> = input
+            .iter()
+            .map(|robot| move_robot(robot, &room_size, i))
+            .collect();
+        render_robots(&robots, &room_size)
+    });
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
+    i as usize
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Puzzle;
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day14.txt";
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = Input;
+    type Params = Params;
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(12, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_one(input, params).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_two(input, params).into()
     }
 }
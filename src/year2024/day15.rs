@@ -1,8 +1,10 @@
-use std::{char, collections::HashSet};
-
-use crate::util::{
-    grid::{Grid, GridIterator},
-    point::{Point, LEFT, RIGHT},
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::{
+        grid::Grid,
+        parse::check_chars,
+        point::{Point, LEFT, RIGHT},
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -12,44 +14,59 @@ pub struct Input {
     moves: Vec<u8>,
 }
 
-pub fn parse<'a>(input: &'a str) -> &'a str {
-    input
+// the warehouse of part two is twice as wide, so both are parsed up front
+pub struct Warehouses {
+    narrow: Input,
+    wide: Input,
 }
 
-pub fn parse_(input: &str) -> Input {
-    let (grid, moves) = input.split_once("\n\n").unwrap();
+pub fn parse(input: &str) -> Result<Warehouses, ParseError> {
+    let narrow = parse_(input)?;
+    let wide = expand(&narrow);
 
-    let grid = Grid::from_str(grid);
-    let pos = grid.find(|c| *c == b'@').unwrap();
+    Ok(Warehouses { narrow, wide })
+}
 
-    Input {
+pub fn parse_(input: &str) -> Result<Input, ParseError> {
+    let (grid, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end(input, "expected the moves after an empty line"))?;
+
+    check_chars(input, grid, "#.O@")?;
+    check_chars(input, moves, "<>^v")?;
+
+    let grid = Grid::parse(grid)?;
+    let pos = grid
+        .find(|c| *c == b'@')
+        .ok_or_else(|| ParseError::at(input, input, "expected a robot '@' in the warehouse"))?;
+
+    Ok(Input {
         pos,
         grid,
-        moves: moves.bytes().filter(|c| *c != b'\n').collect(),
-    }
+        moves: moves.bytes().filter(|c| !c.is_ascii_whitespace()).collect(),
+    })
 }
 
-fn expand(input: &str) -> Input {
-    let (grid, moves) = input.split_once("\n\n").unwrap();
-
-    let wider: String = grid
-        .chars()
-        .map(|c| match c {
-            '#' => "##".to_string(),
-            'O' => "[]".to_string(),
-            '.' => "..".to_string(),
-            '@' => "@.".to_string(),
-            x => x.to_string(),
+fn expand(input: &Input) -> Input {
+    let elements = input
+        .grid
+        .elements
+        .iter()
+        .flat_map(|c| match c {
+            b'#' => *b"##",
+            b'O' => *b"[]",
+            b'@' => *b"@.",
+            _ => *b"..",
         })
         .collect();
 
-    let grid = Grid::from_str(&wider);
-    let pos = grid.find(|c| *c == b'@').unwrap();
-
     Input {
-        pos,
-        grid,
-        moves: moves.bytes().filter(|c| *c != b'\n').collect(),
+        pos: Point::new(input.pos.x * 2, input.pos.y),
+        grid: Grid {
+            elements,
+            size: Point::new(input.grid.size.x * 2, input.grid.size.y),
+        },
+        moves: input.moves.clone(),
     }
 }
 
@@ -68,7 +85,6 @@ fn attempt_move(grid: &mut Grid<u8>, pos: &mut Point, dir: Point) {
     }
 
     if free_spaces == 0 {
-        return;
     } else {
         grid[*pos] = b'.';
         grid[*pos + dir] = b'@';
@@ -87,9 +103,7 @@ fn collect_coordinates(grid: &Grid<u8>, pos: Point, dir: Point) -> Option<Vec<Po
     let mut res = Vec::new();
     let mut seen = Grid::new_with_same_size(grid);
 
-    while !queue.is_empty() {
-        let p = queue.pop().unwrap();
-
+    while let Some(p) = queue.pop() {
         if seen[p] {
             continue;
         }
@@ -170,8 +184,8 @@ fn score(grid: Grid<u8>) -> usize {
         .sum::<i64>() as usize
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let mut input = parse_(input);
+pub fn solve_part_one(input: &Warehouses) -> usize {
+    let mut input = input.narrow.clone();
 
     input
         .moves
@@ -181,8 +195,8 @@ pub fn solve_part_one(input: &str) -> usize {
     score(input.grid)
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    let mut input = expand(input);
+pub fn solve_part_two(input: &Warehouses) -> usize {
+    let mut input = input.wide.clone();
 
     for ele in input.moves.iter() {
         attempt_move_wide(&mut input.grid, &mut input.pos, Point::from_u8(*ele));
@@ -191,41 +205,25 @@ pub fn solve_part_two(input: &str) -> usize {
     score(input.grid)
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day15.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Warehouses;
+    type Params = ();
 
-        assert_eq!(0, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
@@ -1,12 +1,16 @@
 use std::{collections::VecDeque, mem::swap};
 
-use crate::util::{
-    grid::Grid,
-    point::{Point, CARDINALS, RIGHT},
+use crate::{
+    aoc_debug, aoc_trace,
+    solution::{Answer, ParseError, Solution},
+    util::{
+        grid::Grid,
+        point::{Point, CARDINALS, RIGHT},
+    },
 };
 
-pub fn parse(input: &str) -> Grid<u8> {
-    Grid::from_str(input)
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
 }
 
 pub fn solve_part_one(input: &Grid<u8>) -> usize {
@@ -14,7 +18,7 @@ pub fn solve_part_one(input: &Grid<u8>) -> usize {
     let end = Point::new(input.size.x - 2, 1);
     let starting_direction = RIGHT;
 
-    let mut visited = Grid::new_with_same_size(&input);
+    let mut visited = Grid::new_with_same_size(input);
     visited[start] = true;
 
     let mut queue: Vec<Vec<(Point, Point)>> = vec![Vec::new(); 1001];
@@ -79,12 +83,12 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
 
         for (pos, dir) in current {
             // by the design of our algorithm, we have the invariant that if we process a point here, it will be at the shortest distance from the start
-            if let None = visited[pos] {
+            if visited[pos].is_none() {
                 visited[pos] = Some((dir, cost));
             }
 
             if pos == end {
-                visited.print();
+                aoc_trace!("visited:\n{}", visited.render());
                 done = true;
                 break;
             }
@@ -151,51 +155,30 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
         }
     }
 
-    println!("{:?}", visited[Point::new(1, 10)]);
-    println!("{:?}", visited[Point::new(3, 10)]);
-    println!("{:?}", visited[Point::new(4, 7)]);
-    println!("{:?}", visited[Point::new(5, 7)]);
-
-    plot.print();
+    aoc_debug!("best paths:\n{}", plot.render_chars());
 
     count
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+pub struct Puzzle;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day16.txt";
+    type Input<'a> = Grid<u8>;
+    type Params = ();
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(0, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
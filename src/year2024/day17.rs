@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{parse_at, split_once_at},
+};
 
 #[derive(Debug, Clone)]
 pub struct Computer {
@@ -55,8 +58,12 @@ impl Computer {
         self.wrote = true;
     }
 
+    // a / 2^combo, which is zero once the shift is as wide as a
     fn dv(&self, op: u64) -> u64 {
-        self.a / (1 << self.combo(op))
+        u32::try_from(self.combo(op))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
     }
     fn adv(&mut self, op: u64) {
         self.a = self.dv(op);
@@ -82,8 +89,9 @@ impl Computer {
         }
     }
 
+    // the computer halts when there is no whole instruction left at pc
     fn run(&mut self) {
-        while self.pc < self.program.len() {
+        while self.pc + 1 < self.program.len() {
             self.jumped = false;
 
             self.dispatch(self.program[self.pc], self.program[self.pc + 1]);
@@ -94,64 +102,74 @@ impl Computer {
         }
     }
 
-    fn run_cycle(&mut self) -> u64 {
+    fn run_cycle(&mut self) -> Option<u64> {
         let a = self.a;
         self.pc = 0;
         self.jumped = false;
 
-        while self.pc < self.program.len() && self.jumped == false {
+        while self.pc + 1 < self.program.len() && !self.jumped {
             self.dispatch(self.program[self.pc], self.program[self.pc + 1]);
             self.pc += 2;
         }
 
         self.a = a;
-        self.output.pop().unwrap()
+        self.output.pop()
     }
 }
 
-pub fn parse(input: &str) -> Computer {
+// the value of the next line, which looks like "Register A: 729"
+fn value<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    name: &str,
+) -> Result<&'a str, ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, format!("expected {}", name)))?;
+
+    split_once_at(input, line, ": ").map(|(_, value)| value)
+}
+
+pub fn parse(input: &str) -> Result<Computer, ParseError> {
     let mut it = input.lines();
 
-    let a = it
-        .next()
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .parse()
-        .unwrap();
-
-    let b = it
-        .next()
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .parse()
-        .unwrap();
-
-    let c = it
-        .next()
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .parse()
-        .unwrap();
+    let a = parse_at(input, value(input, &mut it, "register A")?)?;
+    let b = parse_at(input, value(input, &mut it, "register B")?)?;
+    let c = parse_at(input, value(input, &mut it, "register C")?)?;
 
     it.next();
 
-    let program = it
-        .next()
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .split(',')
-        .map(|i| i.parse().unwrap())
-        .collect();
-
-    Computer {
+    let text = value(input, &mut it, "the program")?;
+    let mut program: Vec<u64> = Vec::new();
+
+    for (i, field) in text.split(',').enumerate() {
+        let n = parse_at(input, field)?;
+
+        if n > 7 {
+            return Err(ParseError::at(
+                input,
+                field,
+                "expected a number from 0 to 7",
+            ));
+        }
+
+        // adv, bst, out, bdv and cdv take a combo operand, where 7 is reserved
+        if i % 2 == 1 && n == 7 && [0, 2, 5, 6, 7].contains(&program[i - 1]) {
+            return Err(ParseError::at(input, field, "expected a combo operand"));
+        }
+
+        program.push(n);
+    }
+
+    if !program.len().is_multiple_of(2) {
+        return Err(ParseError::at(
+            input,
+            text,
+            "expected pairs of opcode and operand",
+        ));
+    }
+
+    Ok(Computer {
         a,
         b,
         c,
@@ -160,20 +178,15 @@ pub fn parse(input: &str) -> Computer {
         wrote: false,
         program,
         output: Vec::new(),
-    }
+    })
 }
 
-pub fn solve_part_one(input: &Computer) -> usize {
+pub fn solve_part_one(input: &Computer) -> String {
     let mut computer = input.clone();
 
     computer.run();
 
-    println!(
-        "{}",
-        Vec::from_iter(computer.output.iter().map(|s| s.to_string())).join(",")
-    );
-
-    0
+    Vec::from_iter(computer.output.iter().map(|s| s.to_string())).join(",")
 }
 
 // b = a % 8
@@ -207,7 +220,7 @@ fn tree_search(c: &mut Computer, depth: i64) -> Option<u64> {
 
     let mut min = None;
 
-    c.a = c.a << 3;
+    c.a <<= 3;
     let a = c.a;
 
     // println!("called with c.a = {}", c.a);
@@ -220,7 +233,7 @@ fn tree_search(c: &mut Computer, depth: i64) -> Option<u64> {
         // println!("set a to {}", c.a);
         // println!("running one cycle with candidate a: {}", x);
 
-        if x == c.program[depth as usize] {
+        if x == Some(c.program[depth as usize]) {
             if let Some(c) = tree_search(c, depth - 1) {
                 if min.is_none() || c < min.unwrap() {
                     min = Some(c);
@@ -232,51 +245,36 @@ fn tree_search(c: &mut Computer, depth: i64) -> Option<u64> {
     min
 }
 
-pub fn solve_part_two(input: &Computer) -> usize {
+// None when no value of a makes the program output itself
+pub fn solve_part_two(input: &Computer) -> Option<usize> {
     let mut computer = input.clone();
 
     computer.a = 0;
 
     let d = computer.program.len() as i64 - 1;
 
-    tree_search(&mut computer, d as i64).unwrap() as usize
+    tree_search(&mut computer, d).map(|a| a as usize)
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+    type Input<'a> = Computer;
+    type Params = ();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day17.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(0, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(117440, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
@@ -1,24 +1,85 @@
 use std::mem::swap;
 
-use crate::util::{
-    grid::Grid,
-    point::{Point, CARDINALS},
+use crate::{
+    solution::{
+        parameter, parameter_in, unknown_parameter, Answer, Parameters, ParseError, Solution,
+    },
+    util::{
+        grid::Grid,
+        parse::{parse_at, split_once_at},
+        point::{Point, CARDINALS},
+    },
 };
 
-pub fn parse<'a>(input: &'a str) -> &'a str {
+type Input = Vec<Point>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    // the memory space is a square of this width
+    pub size: i64,
+    // part one looks at the memory after this many bytes have fallen
+    pub bytes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            size: 71,
+            bytes: 1024,
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "size" => self.size = parameter_in(name, value, 1..)?,
+            "bytes" => self.bytes = parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
     input
+        .lines()
+        .map(|line| {
+            let (a, b) = split_once_at(input, line, ",")?;
+
+            Ok(Point::new(coordinate(input, a)?, coordinate(input, b)?))
+        })
+        .collect()
+}
+
+fn coordinate(input: &str, text: &str) -> Result<i64, ParseError> {
+    let n = parse_at(input, text)?;
+
+    if n < 0 {
+        return Err(ParseError::at(
+            input,
+            text,
+            "expected a coordinate of at least 0",
+        ));
+    }
+
+    Ok(n)
 }
 
-fn parse_(input: &str, size: Point, num_fallen: usize) -> Grid<u8> {
+// None when a byte falls outside of the memory space
+fn parse_(input: &Input, size: Point, num_fallen: usize) -> Option<Grid<u8>> {
     let mut grid = Grid::new_with_element(size.x, size.y, b'.');
 
-    input.lines().take(num_fallen).for_each(|line| {
-        let (a, b) = line.split_once(',').unwrap();
+    for p in input.iter().take(num_fallen) {
+        if !grid.in_grid(p) {
+            return None;
+        }
 
-        grid[Point::new(a.parse().unwrap(), b.parse().unwrap())] = b'#';
-    });
+        grid[*p] = b'#';
+    }
 
-    grid
+    Some(grid)
 }
 
 fn bfs(grid: &Grid<u8>) -> Option<usize> {
@@ -60,66 +121,53 @@ fn bfs(grid: &Grid<u8>) -> Option<usize> {
     Some(i - 1)
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let grid = parse_(input, Point::new(71, 71), 1024);
-    bfs(&grid).unwrap()
+// None when the exit cannot be reached
+pub fn solve_part_one(input: &Input, params: &Params) -> Option<usize> {
+    let size = Point::new(params.size, params.size);
+    let grid = parse_(input, size, params.bytes)?;
+    bfs(&grid)
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    let mut grid = parse_(input, Point::new(71, 71), 1024);
+pub fn solve_part_two(input: &Input, params: &Params) -> Option<String> {
+    let size = Point::new(params.size, params.size);
+    let mut grid = parse_(input, size, params.bytes)?;
 
-    for line in input.lines().skip(1024) {
-        // place the tile
-        let (a, b) = line.split_once(',').unwrap();
-        let p = Point::new(a.parse().unwrap(), b.parse().unwrap());
+    for p in input.iter().skip(params.bytes) {
+        if !grid.in_grid(p) {
+            return None;
+        }
 
-        grid[p] = b'#';
+        // place the tile
+        grid[*p] = b'#';
 
         // check if it's still reachable
-        if let None = bfs(&grid) {
-            println!("{}", line);
-            break;
+        if bfs(&grid).is_none() {
+            return Some(format!("{},{}", p.x, p.y));
         }
     }
 
-    0
-}
-
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
+    None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day18.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "RAM Run";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = Params;
 
-        assert_eq!(22, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_one(input, params).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_two(input, params).into()
     }
 }
@@ -1,15 +1,17 @@
+use crate::solution::{Answer, ParseError, Solution};
+
 #[derive(Debug)]
 pub struct Input {
     patterns: Vec<String>,
     designs: Vec<String>,
 }
 
-pub fn parse(input: &str) -> Input {
+pub fn parse(input: &str) -> Result<Input, ParseError> {
     let mut iter = input.lines();
 
     let patterns = iter
         .next()
-        .unwrap()
+        .ok_or_else(|| ParseError::end(input, "expected the towel patterns"))?
         .split(", ")
         .map(|s| s.to_string())
         .collect();
@@ -19,10 +21,10 @@ pub fn parse(input: &str) -> Input {
 
     let designs = iter.map(|s| s.to_string()).collect();
 
-    Input { patterns, designs }
+    Ok(Input { patterns, designs })
 }
 
-fn assemble(patterns: &Vec<String>, design: &String) -> usize {
+fn assemble(patterns: &[String], design: &str) -> usize {
     let mut reachable = vec![0usize; design.len() + 1];
     reachable[0] = 1;
 
@@ -67,41 +69,25 @@ pub fn solve_part_two(input: &Input) -> usize {
         .sum()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day19.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Linen Layout";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(6, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(16, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
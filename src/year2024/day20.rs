@@ -1,9 +1,21 @@
-use std::collections::HashMap;
-
-use crate::util::{grid::Grid, point::Point, point::CARDINALS};
+use crate::{
+    solution::{parameter_in, unknown_parameter, Answer, Parameters, ParseError, Solution},
+    util::{grid::Grid, point::Point, point::CARDINALS},
+};
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::parse(input)?;
+
+    for c in [b'S', b'E'] {
+        if grid.find(|x| *x == c).is_none() {
+            return Err(ParseError::end(
+                input,
+                format!("expected '{}' on the map", c as char),
+            ));
+        }
+    }
 
-pub fn parse(input: &str) -> Grid<u8> {
-    Grid::from_str(input)
+    Ok(grid)
 }
 
 // generate all points at manhattan distance less than distance
@@ -45,7 +57,30 @@ impl Iterator for ManhattanIterator {
     }
 }
 
-pub fn solve_part_one(input: &Grid<u8>) -> usize {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    // only cheats that save at least this many picoseconds are counted
+    pub saving: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { saving: 100 }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "saving" => self.saving = parameter_in(name, value, 1..)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn solve_part_one(input: &Grid<u8>, params: &Params) -> usize {
     let start = input.find(|c| *c == b'S').unwrap();
     let end = input.find(|c| *c == b'E').unwrap();
 
@@ -54,7 +89,7 @@ pub fn solve_part_one(input: &Grid<u8>) -> usize {
 
     let mut count = 0;
 
-    let mut visited = Grid::new_with_same_size(&input);
+    let mut visited = Grid::new_with_same_size(input);
     visited[start] = Some(distance);
 
     // walk through grid from start
@@ -76,8 +111,8 @@ pub fn solve_part_one(input: &Grid<u8>) -> usize {
 
         // check for shortcuts
 
-        // if the distance is < 100 we can't have a fast shortcut
-        if distance < 100 {
+        // if the distance is < saving we can't have a fast shortcut
+        if distance < params.saving {
             continue;
         }
 
@@ -92,7 +127,7 @@ pub fn solve_part_one(input: &Grid<u8>) -> usize {
                 && visited[candidate].is_some()
             {
                 if let Some(distance_previous) = visited[candidate] {
-                    if distance - distance_previous - 2 >= 100 {
+                    if distance - distance_previous - 2 >= params.saving {
                         count += 1;
                     }
                 }
@@ -103,7 +138,7 @@ pub fn solve_part_one(input: &Grid<u8>) -> usize {
     count
 }
 
-pub fn solve_part_two(input: &Grid<u8>) -> usize {
+pub fn solve_part_two(input: &Grid<u8>, params: &Params) -> usize {
     let grid = input;
     let start = grid.find(|c| *c == b'S').unwrap();
     let end = grid.find(|c| *c == b'E').unwrap();
@@ -113,7 +148,7 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
 
     let mut count = 0;
 
-    let mut visited = Grid::new_with_same_size(&grid);
+    let mut visited = Grid::new_with_same_size(grid);
     visited[start] = Some(distance);
 
     // let mut dbg_map: HashMap<i64, i64> = HashMap::new();
@@ -137,8 +172,8 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
 
         // check for shortcuts
 
-        // if the distance is < 100 we can't have a fast shortcut
-        if distance < 100 {
+        // if the distance is < saving we can't have a fast shortcut
+        if distance < params.saving {
             continue;
         }
 
@@ -153,7 +188,7 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
                 && visited[candidate].is_some()
             {
                 if let Some(distance_previous) = visited[candidate] {
-                    if distance - distance_previous - manhattan >= 100 {
+                    if distance - distance_previous - manhattan >= params.saving {
                         count += 1;
                     }
 
@@ -178,41 +213,25 @@ pub fn solve_part_two(input: &Grid<u8>) -> usize {
     count
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day20.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Race Condition";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Grid<u8>;
+    type Params = Params;
 
-        assert_eq!(0, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_one(input, params).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, params: &Self::Params) -> Answer {
+        solve_part_two(input, params).into()
     }
 }
@@ -1,29 +1,40 @@
-use std::{cmp, collections::HashMap, mem::swap};
+use std::collections::HashMap;
 
-fn keypad_coordinate(c: char) -> (i8, i8) {
+use crate::{
+    aoc_debug, aoc_trace,
+    solution::{Answer, ParseError, Solution},
+    util::{
+        parse::{check_chars, parse_at, split_once_at},
+        point::Point,
+    },
+};
+
+type Key = Point<i8>;
+
+fn keypad_coordinate(c: char) -> Key {
     match c {
-        '7' => (0, 0),
-        '8' => (1, 0),
-        '9' => (2, 0),
-        '4' => (0, 1),
-        '5' => (1, 1),
-        '6' => (2, 1),
-        '1' => (0, 2),
-        '2' => (1, 2),
-        '3' => (2, 2),
-        '0' => (1, 3),
-        'A' => (2, 3),
+        '7' => Key::new(0, 0),
+        '8' => Key::new(1, 0),
+        '9' => Key::new(2, 0),
+        '4' => Key::new(0, 1),
+        '5' => Key::new(1, 1),
+        '6' => Key::new(2, 1),
+        '1' => Key::new(0, 2),
+        '2' => Key::new(1, 2),
+        '3' => Key::new(2, 2),
+        '0' => Key::new(1, 3),
+        'A' => Key::new(2, 3),
         _ => panic!("character not on keypad"),
     }
 }
 
-fn arrowkeys_coordinate(c: char) -> (i8, i8) {
+fn arrowkeys_coordinate(c: char) -> Key {
     match c {
-        '^' => (1, 0),
-        'A' => (2, 0),
-        '<' => (0, 1),
-        'v' => (1, 1),
-        '>' => (2, 1),
+        '^' => Key::new(1, 0),
+        'A' => Key::new(2, 0),
+        '<' => Key::new(0, 1),
+        'v' => Key::new(1, 1),
+        '>' => Key::new(2, 1),
         _ => panic!("character not on arrow keys"),
     }
 }
@@ -32,31 +43,33 @@ fn calculate_paths() -> HashMap<(char, char), Vec<String>> {
     let mut paths = HashMap::new();
 
     let numpad_chars = vec!['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A'];
-    let numpad_gap = (0, 3);
+    let numpad_gap = Key::new(0, 3);
 
     let arrow_chars = vec!['<', '>', '^', 'v', 'A'];
-    let arrow_gap = (0, 0);
+    let arrow_gap = Key::ORIGIN;
 
     for a in &numpad_chars {
         for b in &numpad_chars {
-            let (ax, ay) = keypad_coordinate(*a);
-            let (bx, by) = keypad_coordinate(*b);
+            let a_key = keypad_coordinate(*a);
+            let b_key = keypad_coordinate(*b);
 
-            let xs = if bx - ax < 0 {
-                "<".repeat((ax - bx) as usize)
+            let Point { x: dx, y: dy } = b_key - a_key;
+
+            let xs = if dx < 0 {
+                "<".repeat(-dx as usize)
             } else {
-                ">".repeat((bx - ax) as usize)
+                ">".repeat(dx as usize)
             };
 
-            let ys = if by - ay < 0 {
-                "^".repeat((ay - by) as usize)
+            let ys = if dy < 0 {
+                "^".repeat(-dy as usize)
             } else {
-                "v".repeat((by - ay) as usize)
+                "v".repeat(dy as usize)
             };
 
-            if (ax, by) == numpad_gap {
+            if Key::new(a_key.x, b_key.y) == numpad_gap {
                 paths.insert((*a, *b), vec![xs + &ys + "A"]);
-            } else if (bx, ay) == numpad_gap {
+            } else if Key::new(b_key.x, a_key.y) == numpad_gap {
                 paths.insert((*a, *b), vec![ys + &xs + "A"]);
             } else {
                 if xs.is_empty() {
@@ -72,24 +85,26 @@ fn calculate_paths() -> HashMap<(char, char), Vec<String>> {
 
     for a in &arrow_chars {
         for b in &arrow_chars {
-            let (ax, ay) = arrowkeys_coordinate(*a);
-            let (bx, by) = arrowkeys_coordinate(*b);
+            let a_key = arrowkeys_coordinate(*a);
+            let b_key = arrowkeys_coordinate(*b);
+
+            let Point { x: dx, y: dy } = b_key - a_key;
 
-            let xs = if bx - ax < 0 {
-                "<".repeat((ax - bx) as usize)
+            let xs = if dx < 0 {
+                "<".repeat(-dx as usize)
             } else {
-                ">".repeat((bx - ax) as usize)
+                ">".repeat(dx as usize)
             };
 
-            let ys = if by - ay < 0 {
-                "^".repeat((ay - by) as usize)
+            let ys = if dy < 0 {
+                "^".repeat(-dy as usize)
             } else {
-                "v".repeat((by - ay) as usize)
+                "v".repeat(dy as usize)
             };
 
-            if (ax, by) == arrow_gap {
+            if Key::new(a_key.x, b_key.y) == arrow_gap {
                 paths.insert((*a, *b), vec![xs + &ys + "A"]);
-            } else if (bx, ay) == arrow_gap {
+            } else if Key::new(b_key.x, a_key.y) == arrow_gap {
                 paths.insert((*a, *b), vec![ys + &xs + "A"]);
             } else {
                 if xs.is_empty() {
@@ -108,7 +123,7 @@ fn calculate_paths() -> HashMap<(char, char), Vec<String>> {
 
 fn get_costs(
     paths: &HashMap<(char, char), Vec<String>>,
-    mut costs: &mut Vec<HashMap<(char, char), usize>>,
+    costs: &mut Vec<HashMap<(char, char), usize>>,
     seq: &str,
     depth: usize,
 ) -> usize {
@@ -132,7 +147,7 @@ fn get_costs(
 
             let min = v
                 .iter()
-                .map(|s| get_costs(paths, &mut costs, s, depth - 1))
+                .map(|s| get_costs(paths, costs, s, depth - 1))
                 .min()
                 .unwrap_or(0);
             costs[depth].insert((prev, c), min);
@@ -178,86 +193,85 @@ fn calculate_costs(seq: &str, paths: &HashMap<(char, char), Vec<String>>, depth:
     total
 }
 
-pub fn parse<'a>(input: &'a str) -> &'a str {
-    input
+// a door code like 029A, with its numeric part
+#[derive(Debug)]
+pub struct Code<'a> {
+    keys: &'a str,
+    num: usize,
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let paths = calculate_paths();
-
-    println!("{:?}", paths);
+pub fn parse(input: &str) -> Result<Vec<Code<'_>>, ParseError> {
+    check_chars(input, input, "0123456789A")?;
 
     input
         .lines()
         .map(|line| {
-            let num = line.split_once("A").unwrap().0.parse::<usize>().unwrap();
-
-            //            let line = "0";
+            let (num, _) = split_once_at(input, line, "A")?;
 
-            let l = calculate_costs(line, &paths, 3);
-            println!("num: {num}, len: {}", l);
-
-            num * l
+            Ok(Code {
+                keys: line,
+                num: parse_at(input, num)?,
+            })
         })
-        .sum()
+        .collect()
 }
 
-pub fn solve_part_two(input: &str) -> usize {
+pub fn solve_part_one(input: &[Code]) -> usize {
     let paths = calculate_paths();
 
-    println!("{:?}", paths);
+    aoc_trace!("paths: {:?}", paths);
 
     input
-        .lines()
-        .map(|line| {
-            let num = line.split_once("A").unwrap().0.parse::<usize>().unwrap();
-
+        .iter()
+        .map(|code| {
             //            let line = "0";
 
-            let l = calculate_costs(line, &paths, 26);
-            println!("num: {num}, len: {}", l);
+            let l = calculate_costs(code.keys, &paths, 3);
+            aoc_debug!("num: {}, len: {}", code.num, l);
 
-            num * l
+            code.num * l
         })
         .sum()
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub fn solve_part_two(input: &[Code]) -> usize {
+    let paths = calculate_paths();
 
-    let input = parse(input);
+    aoc_trace!("paths: {:?}", paths);
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
+    input
+        .iter()
+        .map(|code| {
+            //            let line = "0";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            let l = calculate_costs(code.keys, &paths, 26);
+            aoc_debug!("num: {}, len: {}", code.num, l);
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day21.txt";
+            code.num * l
+        })
+        .sum()
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+pub struct Puzzle;
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
 
-        assert_eq!(0, result);
-    }
+    type Input<'a> = Vec<Code<'a>>;
+    type Params = ();
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
 
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
 
@@ -1,6 +1,8 @@
-use std::{
-    num,
-    sync::{Arc, Mutex},
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::parse_at,
 };
 
 type Input = Vec<u32>;
@@ -18,8 +20,8 @@ fn to_index(x: u32, y: u32, z: u32, w: u32) -> usize {
 }
 
 // create the difference vector and the sum
-pub fn parse(input: &str) -> Vec<u32> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input.lines().map(|line| parse_at(input, line)).collect()
 }
 
 pub fn solve_part_one(input: &Input) -> usize {
@@ -43,7 +45,7 @@ fn compute_difference(a: u32, b: u32) -> u32 {
 }
 
 // serial code is pretty okay, let's try parallel
-pub fn solve_part_two<'a>(input: &'a Input) -> usize {
+pub fn solve_part_two(input: &Input) -> usize {
     //let mut res = vec![0; 130321];
     let num_threads = 8;
 
@@ -52,7 +54,7 @@ pub fn solve_part_two<'a>(input: &'a Input) -> usize {
     let res = Arc::new(Mutex::new(vec![0; 130321]));
 
     std::thread::scope(|s| {
-        for (i, chunk) in chunks.enumerate() {
+        for chunk in chunks {
             let res = Arc::clone(&res);
 
             s.spawn(move || {
@@ -100,16 +102,27 @@ pub fn solve_part_two<'a>(input: &'a Input) -> usize {
     *v.iter().max().unwrap() as usize
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Monkey Market";
 
-    let input = parse(input);
+    type Input<'a> = Input;
+    type Params = ();
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
 // the last digits are unaffected by the left shifts!
@@ -122,30 +135,3 @@ pub fn solve(filename: &str) -> Result<(String, String), String> {
 // algorithmic approaches (other than brute force): ???
 //
 // need to check 130321 sequences in total
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day22.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(37327623, result);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
-
-        assert_eq!(23, result);
-    }
-}
@@ -1,11 +1,17 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-    mem::swap,
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::split_once_at,
 };
 
-pub fn parse<'a>(input: &'a str) -> &'a str {
-    input
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    // the connections are only checked here, the parts read them again
+    for line in input.lines() {
+        split_once_at(input, line, "-")?;
+    }
+
+    Ok(input)
 }
 
 fn parse_graph(input: &str) -> HashMap<String, Vec<String>> {
@@ -44,20 +50,15 @@ pub fn solve_part_one(input: &str) -> usize {
     let mut res = 0;
 
     for (node, edges) in wedges.iter() {
-        let d = edges.len();
-
         for (i, u) in edges.iter().enumerate() {
             for v in edges.iter().skip(i) {
                 // test if wedge is closed
                 let es = graph.get(v).unwrap();
 
-                if es.iter().position(|w| w == u).is_some() {
-                    if node.chars().next().unwrap() == 't'
-                        || u.chars().next().unwrap() == 't'
-                        || v.chars().next().unwrap() == 't'
-                    {
-                        res += 1;
-                    }
+                if es.iter().position(|w| w == u).is_some()
+                    && (node.starts_with('t') || u.starts_with('t') || v.starts_with('t'))
+                {
+                    res += 1;
                 }
             }
         }
@@ -100,53 +101,35 @@ fn bron_kerbosch(
     }
 }
 
-pub fn solve_part_two(input: &str) -> usize {
+pub fn solve_part_two(input: &str) -> String {
     let graph = parse_graph(input);
     let mut res = Vec::new();
     let p = HashSet::from_iter(graph.keys().cloned());
 
     bron_kerbosch(&graph, HashSet::new(), p, HashSet::new(), &mut res);
 
-    println!("{}", res.iter().max_by_key(|clique| clique.len()).unwrap());
-
-    0
-}
-
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(input).to_string(),
-        solve_part_two(input).to_string(),
-    ))
+    res.into_iter().max_by_key(|clique| clique.len()).unwrap()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Puzzle;
 
-    static TEST_DATA_PATH: &str = "data/test/year2024/day23.txt";
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "LAN Party";
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = &'a str;
+    type Params = ();
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(7, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
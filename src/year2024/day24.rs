@@ -1,37 +1,56 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{parse_at, split_once_at},
+};
+
 #[derive(Debug, Clone)]
 pub struct Input {
     values: HashMap<String, u8>,
     operations: Vec<(String, String, String, String)>,
 }
 
-pub fn parse(input: &str) -> Input {
-    let (initial, ops) = input.split_once("\n\n").unwrap();
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (initial, ops) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end(input, "expected the gates after an empty line"))?;
 
     let mut values = HashMap::new();
 
-    initial.lines().for_each(|s| {
-        let (id, v) = s.split_once(": ").unwrap();
+    for s in initial.lines() {
+        let (id, v) = split_once_at(input, s, ": ")?;
 
-        values.insert(id.to_string(), v.parse().unwrap());
-    });
+        values.insert(id.to_string(), parse_at(input, v)?);
+    }
 
     let operations = ops
         .lines()
         .map(|line| {
             let op: Vec<_> = line.split(' ').collect();
 
-            (
-                op[1].to_string(),
-                op[0].to_string(),
-                op[2].to_string(),
-                op[4].to_string(),
-            )
+            let [a, gate, b, "->", out] = op[..] else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected a gate like \"a AND b -> c\"",
+                ));
+            };
+
+            if !["AND", "OR", "XOR"].contains(&gate) {
+                return Err(ParseError::at(input, gate, "unknown gate"));
+            }
+
+            Ok((
+                gate.to_string(),
+                a.to_string(),
+                b.to_string(),
+                out.to_string(),
+            ))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Input { values, operations }
+    Ok(Input { values, operations })
 }
 
 fn run_circuit(values: &mut HashMap<String, u8>, ops: &[(String, String, String, String)]) {
@@ -72,8 +91,8 @@ fn score(values: &HashMap<String, u8>) -> usize {
     let mut res = 0;
 
     values.iter().for_each(|(k, v)| {
-        if k.starts_with('z') {
-            let s: usize = k[1..].parse().unwrap();
+        if let Some(s) = k.strip_prefix('z') {
+            let s: usize = s.parse().unwrap();
             let v = *v as usize;
 
             res |= v << s;
@@ -149,41 +168,25 @@ pub fn solve_part_two(input: &Input) -> String {
     res.join(",")
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Crossed Wires";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day23.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = Input;
+    type Params = ();
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(2024, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!("", result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
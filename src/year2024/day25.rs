@@ -1,3 +1,8 @@
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::check_chars,
+};
+
 type Pins = [u8; 5];
 
 #[derive(Debug)]
@@ -6,18 +11,29 @@ pub struct Input {
     keys: Vec<Pins>,
 }
 
-pub fn parse(input: &str) -> Input {
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    check_chars(input, input, "#.")?;
+
     let mut locks = Vec::new();
     let mut keys = Vec::new();
-    input.split("\n\n").for_each(|s| {
+
+    for s in input.trim_end().split("\n\n") {
         let mut u: Pins = [0; 5];
-        let mut it = s.lines();
+        let rows: Vec<_> = s.lines().collect();
+
+        if rows.len() != 7 {
+            return Err(ParseError::at(
+                input,
+                s,
+                "expected a schematic of seven rows",
+            ));
+        }
 
         // skip first line
-        it.next();
-
-        for _ in 0..5 {
-            let l = it.next().unwrap();
+        for l in &rows[1..6] {
+            if l.len() != u.len() {
+                return Err(ParseError::at(input, l, "expected a row of five pins"));
+            }
 
             for (i, j) in l.bytes().enumerate() {
                 if j == b'#' {
@@ -32,9 +48,9 @@ pub fn parse(input: &str) -> Input {
         } else {
             locks.push(u);
         }
-    });
+    }
 
-    Input { locks, keys }
+    Ok(Input { locks, keys })
 }
 
 fn compatible(lock: &Pins, key: &Pins) -> bool {
@@ -61,45 +77,30 @@ pub fn solve_part_one(input: &Input) -> usize {
     res
 }
 
-pub fn solve_part_two(_: &Input) -> usize {
-    0
+// there is no second puzzle on the last day
+pub fn solve_part_two(_: &Input) -> Answer {
+    Answer::Unsolved
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
+pub struct Puzzle;
 
-    let input = parse(input);
+impl Solution for Puzzle {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Code Chronicle";
 
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2024/day25.txt";
-
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+    type Input<'a> = Input;
+    type Params = ();
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
-
-        assert_eq!(3, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(0, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input)
     }
 }
@@ -1,11 +1,16 @@
-use crate::util::{self, parse::ParseSigned};
+use crate::{
+    solution::{Answer, ParseError, Solution},
+    util::parse::{check_chars, ParseSigned},
+};
 
 const DIAL_START: i32 = 50;
 
 type Input = Vec<i32>;
 
-pub fn parse(input: &str) -> Input {
-    ParseSigned::new_with_sign(input.bytes(), b'L').collect()
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    check_chars(input, input, "LR0123456789")?;
+
+    Ok(ParseSigned::new_with_sign(input.bytes(), b'L').collect())
 }
 
 pub fn solve_part_one(input: &[i32]) -> usize {
@@ -30,41 +35,25 @@ pub fn solve_part_two(input: &[i32]) -> usize {
         .0 as usize
 }
 
-pub fn solve(filename: &str) -> Result<(String, String), String> {
-    let input =
-        &std::fs::read_to_string(filename).or(Err(format!("could not read file {}", filename)))?;
-
-    let input = parse(input);
-
-    Ok((
-        solve_part_one(&input).to_string(),
-        solve_part_two(&input).to_string(),
-    ))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static TEST_DATA_PATH: &str = "data/test/year2025/day01.txt";
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_one() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
+impl Solution for Puzzle {
+    const YEAR: u32 = 2025;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Secret Entrance";
 
-        let input = parse(input);
-        let result = solve_part_one(&input);
+    type Input<'a> = Input;
+    type Params = ();
 
-        assert_eq!(3, result);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = &std::fs::read_to_string(TEST_DATA_PATH).expect("Test data does not exist.");
-
-        let input = parse(input);
-        let result = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_one(input).into()
+    }
 
-        assert_eq!(6, result);
+    fn part_two(input: &Self::Input<'_>, _: &Self::Params) -> Answer {
+        solve_part_two(input).into()
     }
 }
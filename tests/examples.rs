@@ -0,0 +1,195 @@
+// runs the solver of every example in data/test/yearYYYY/dayDD[-N].txt and compares the answers
+// with the sidecar dayDD[-N].expected next to it, every example is reported as its own test
+//
+// examples without a sidecar are reported as ignored, parts without an expected answer are not
+// checked, the [params] section of a sidecar is passed to the solver
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use rust_aoc::{answers::parse_sidecar, examples, input::normalize, Answer};
+
+struct Example {
+    name: String,
+    year: u32,
+    day: u32,
+    path: PathBuf,
+}
+
+fn example(year: u32, path: &Path) -> Option<Example> {
+    Some(Example {
+        name: format!("year{}::{}", year, path.file_stem()?.to_str()?),
+        year,
+        day: examples::day(path)?,
+        path: path.to_path_buf(),
+    })
+}
+
+fn discover(root: &Path) -> Vec<Example> {
+    let mut examples = Vec::new();
+
+    for dir in fs::read_dir(root).expect("data/test exists").flatten() {
+        let name = dir.file_name();
+        let Some(year) = name.to_str().and_then(|s| s.strip_prefix("year")) else {
+            continue;
+        };
+        let Ok(year) = year.parse() else {
+            continue;
+        };
+
+        for file in fs::read_dir(dir.path()).into_iter().flatten().flatten() {
+            examples.extend(example(year, &file.path()));
+        }
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+enum Outcome {
+    Passed,
+    Ignored,
+    Failed(String),
+}
+
+fn run(example: &Example) -> Outcome {
+    let Ok(expected) = fs::read_to_string(example.path.with_extension("expected")) else {
+        return Outcome::Ignored;
+    };
+
+    let sidecar = match parse_sidecar(&expected) {
+        Ok(sidecar) => sidecar,
+        Err(e) => return Outcome::Failed(format!("invalid expected answers: {}", e)),
+    };
+
+    let Some(solver) = rust_aoc::find(example.year, example.day) else {
+        return Outcome::Failed("no solver for this day".to_string());
+    };
+
+    let input = match fs::read_to_string(&example.path) {
+        Ok(input) => normalize(&input).0,
+        Err(_) => return Outcome::Failed(format!("could not read {}", example.path.display())),
+    };
+
+    let answers = match panic::catch_unwind(AssertUnwindSafe(|| {
+        solver.solve_with(&input, &sidecar.params)
+    })) {
+        Ok(Ok((part1, part2))) => [part1, part2],
+        Ok(Err(e)) => return Outcome::Failed(e.to_string()),
+        Err(_) => return Outcome::Failed("the solver panicked".to_string()),
+    };
+
+    let mismatches: Vec<String> = ["part one", "part two"]
+        .iter()
+        .zip(sidecar.expected.iter().zip(&answers))
+        .filter_map(|(part, (expected, answer))| {
+            let expected = expected.as_deref()?;
+            let answer = Answer::to_string(answer);
+
+            (expected != answer).then(|| format!("{}: expected {}, got {}", part, expected, answer))
+        })
+        .collect();
+
+    if mismatches.is_empty() {
+        Outcome::Passed
+    } else {
+        Outcome::Failed(mismatches.join("\n"))
+    }
+}
+
+// the options of the default test harness that are followed by a value
+const OPTIONS_WITH_VALUE: &[&str] = &["--test-threads", "--skip", "--format", "--color", "-Z"];
+
+fn main() -> ExitCode {
+    let mut filters = Vec::new();
+    let mut skips = Vec::new();
+    let mut list = false;
+    let mut exact = false;
+
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" => list = true,
+            "--exact" => exact = true,
+            "--skip" => skips.extend(args.next()),
+            a if OPTIONS_WITH_VALUE.contains(&a) => {
+                args.next();
+            }
+            a if a.starts_with('-') => {}
+            _ => filters.push(arg),
+        }
+    }
+
+    let matches = |name: &str, pattern: &String| {
+        if exact {
+            name == pattern
+        } else {
+            name.contains(pattern.as_str())
+        }
+    };
+
+    let examples: Vec<Example> = discover(Path::new("data/test"))
+        .into_iter()
+        .filter(|e| filters.is_empty() || filters.iter().any(|f| matches(&e.name, f)))
+        .filter(|e| !skips.iter().any(|s| matches(&e.name, s)))
+        .collect();
+
+    if list {
+        for example in &examples {
+            println!("{}: test", example.name);
+        }
+
+        return ExitCode::SUCCESS;
+    }
+
+    println!("\nrunning {} tests", examples.len());
+
+    let (mut passed, mut ignored) = (0, 0);
+    let mut failures = Vec::new();
+
+    for example in &examples {
+        let status = match run(example) {
+            Outcome::Passed => {
+                passed += 1;
+                "ok"
+            }
+            Outcome::Ignored => {
+                ignored += 1;
+                "ignored"
+            }
+            Outcome::Failed(message) => {
+                failures.push((&example.name, message));
+                "FAILED"
+            }
+        };
+
+        println!("test {} ... {}", example.name, status);
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:\n");
+
+        for (name, message) in &failures {
+            println!("---- {} ----\n{}\n", name, message);
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} ignored\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        passed,
+        failures.len(),
+        ignored
+    );
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}